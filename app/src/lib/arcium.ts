@@ -0,0 +1,52 @@
+// src/lib/arcium.ts
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import { ARCIUM_CLUSTER_OFFSET, ARCIUM_MXE_PUBKEY, POKER_PROGRAM_ID } from './constants';
+
+export const ARCIUM_PROGRAM_ID = new PublicKey('Arcj82pX7HxYKLR92qvgZUAd7vGS1k4hQvAFcPATFdEQ');
+
+const u32le = (n: number) => {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(n);
+    return buf;
+};
+
+const arciumPda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, ARCIUM_PROGRAM_ID)[0];
+
+/**
+ * Offset of a circuit's computation definition: the first 4 bytes of
+ * sha256(circuit name), little-endian — same as `comp_def_offset` on-chain.
+ */
+export function compDefOffset(circuit: string): number {
+    return Buffer.from(anchor.utils.sha256.hash(circuit), 'hex').readUInt32LE(0);
+}
+
+/** Random u64 identifying one queued computation */
+export function newComputationOffset(): anchor.BN {
+    return new anchor.BN(Buffer.from(crypto.getRandomValues(new Uint8Array(8))));
+}
+
+/**
+ * Accounts every instruction that queues an Arcium computation takes.
+ * The sign PDA, fee pool and clock resolve from the IDL.
+ */
+export function arciumAccounts(circuit: string, computationOffset: anchor.BN) {
+    const cluster = u32le(ARCIUM_CLUSTER_OFFSET);
+    return {
+        mxeAccount: ARCIUM_MXE_PUBKEY,
+        mempoolAccount: arciumPda(Buffer.from('Mempool'), cluster),
+        executingPool: arciumPda(Buffer.from('Execpool'), cluster),
+        computationAccount: arciumPda(
+            Buffer.from('ComputationAccount'),
+            cluster,
+            computationOffset.toArrayLike(Buffer, 'le', 8)
+        ),
+        compDefAccount: arciumPda(
+            Buffer.from('ComputationDefinitionAccount'),
+            POKER_PROGRAM_ID.toBuffer(),
+            u32le(compDefOffset(circuit))
+        ),
+        clusterAccount: arciumPda(Buffer.from('Cluster'), cluster),
+    };
+}
@@ -9,6 +9,9 @@ export const ARCIUM_MXE_PUBKEY = new PublicKey(
   import.meta.env.VITE_ARCIUM_MXE_PUBKEY || 'H8MGCGH5psG6dss4nZkJWazWEbRzvJG7Kbd2MAzK9n4x'
 );
 
+// Cluster the MXE computes on; the mempool, pools and computation accounts derive from it
+export const ARCIUM_CLUSTER_OFFSET = Number(import.meta.env.VITE_ARCIUM_CLUSTER_OFFSET || 0);
+
 export const SOLANA_RPC_URL = import.meta.env.VITE_SOLANA_RPC_URL || 'https://api.devnet.solana.com';
 export const SOLANA_NETWORK = import.meta.env.VITE_SOLANA_NETWORK || 'devnet';
 
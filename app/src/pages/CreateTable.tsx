@@ -105,6 +105,10 @@ export const CreateTablePage: React.FC = () => {
         new anchor.BN(form.bigBlind),
         form.minPlayers,
         form.maxPlayers,
+        { noLimit: {} },
+        // No antes, straddle or bomb pots
+        { ante: new anchor.BN(0), bigBlindAnte: false, straddle: false, bombPotAnte: new anchor.BN(0) },
+        new anchor.BN(0), // no timeout reward
         tokenMint,
         new anchor.BN(form.tokenAmount)
       )
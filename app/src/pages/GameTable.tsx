@@ -11,11 +11,13 @@ import {
 } from '../types';
 import { evaluateHand } from '../lib/hand-evaluator';
 import * as anchor from '@coral-xyz/anchor';
-import { POKER_PROGRAM_ID, ARCIUM_MXE_PUBKEY, SOLANA_NETWORK } from '../lib/constants';
+import { x25519 } from '@arcium-hq/client';
+import { POKER_PROGRAM_ID, SOLANA_NETWORK } from '../lib/constants';
 import idl from '../idl/encrypted_poker.json';
 import { PublicKey } from '@solana/web3.js';
 import { parseTxError } from '../lib/parseTxError';
-import { getStandinCards, isCardRevealed, getStandinHoleCards, buildDeck } from '../lib/card-utils';
+import { arciumAccounts, newComputationOffset } from '../lib/arcium';
+import { getStandinCards, isCardRevealed, getStandinHoleCards } from '../lib/card-utils';
 
 // forceReveal: only affects HOLE CARDS (we always show the player their own cards).
 // Community cards are still gated by game phase so they appear at the right street.
@@ -423,70 +425,12 @@ const BetControls: React.FC<{
 
 // ===== Main page =====
 
-// =====================================================================
-// ORACLE SIMULATION HELPERS
-// Because Arcium MPC is not live in the demo, the frontend has to act
-// as the oracle and call the on_community_cards / on_showdown_result
-// callbacks itself, immediately after each matching instruction.
-// =====================================================================
-
-/** Simulate hole cards for any player — unique per wallet × table × hand */
-function simulateHoleCards(walletBytes: Uint8Array, tableId: string, handNum: number): [number, number] {
-  const suffix = tableId.replace('table-', '');
-  const sb = new TextEncoder().encode(suffix);
-  const tb0 = sb[0] ?? 3;
-  const tb1 = sb[1] ?? 17;
-  const tb2 = sb[2] ?? 31;
-  const c1 = Math.abs((walletBytes[0] ^ walletBytes[4] ^ tb0 ^ (handNum & 0xff))) % 52;
-  const c2r = Math.abs((walletBytes[1] ^ walletBytes[5] ^ tb1 ^ tb2 ^ ((handNum >> 8) & 0xff) ^ 7)) % 52;
-  const c2 = c2r === c1 ? (c2r + 1) % 52 : c2r;
-  return [c1, c2];
-}
-
-/** Score a poker hand (higher = better) — checks pairs/sets against 7-card pool */
-function scoreHand(cards: number[]): number {
-  const v = cards.filter(c => c >= 0 && c < 52);
-  if (v.length === 0) return 0;
-  const ranks = v.map(c => c % 13);
-  const suits = v.map(c => Math.floor(c / 13));
-  const rankCnt: Record<number, number> = {};
-  const suitCnt: Record<number, number> = {};
-  ranks.forEach(r => { rankCnt[r] = (rankCnt[r] || 0) + 1; });
-  suits.forEach(s => { suitCnt[s] = (suitCnt[s] || 0) + 1; });
-  const byCount = Object.values(rankCnt).sort((a, b) => b - a);
-  const hasFlush = Object.values(suitCnt).some(n => n >= 5);
-  const sortedRanks = [...new Set(ranks)].sort((a, b) => a - b);
-  let straight = false;
-  for (let i = 0; i <= sortedRanks.length - 5; i++) {
-    if (sortedRanks[i + 4] - sortedRanks[i] === 4) { straight = true; break; }
-  }
-  // Broadway straight (10-J-Q-K-A)
-  if ([0, 9, 10, 11, 12].every(r => sortedRanks.includes(r))) straight = true;
-  const rankMax = Math.max(...ranks);
-  const sum = ranks.reduce((s, r) => s + r, 0);
-
-  if (straight && hasFlush) return 8e5 + rankMax;
-  if (byCount[0] === 4) return 7e5 + sum;
-  if (byCount[0] === 3 && byCount[1] === 2) return 6e5 + sum;
-  if (hasFlush) return 5e5 + sum;
-  if (straight) return 4e5 + rankMax;
-  if (byCount[0] === 3) return 3e5 + sum;
-  if (byCount[0] === 2 && byCount[1] === 2) return 2e5 + sum;
-  if (byCount[0] === 2) return 1e5 + sum;
-  return sum;
-}
-
-function handCategoryFromScore(score: number): number {
-  if (score >= 8e5) return 8; // Straight flush
-  if (score >= 7e5) return 7; // Four of a kind
-  if (score >= 6e5) return 6; // Full house
-  if (score >= 5e5) return 5; // Flush
-  if (score >= 4e5) return 4; // Straight
-  if (score >= 3e5) return 3; // Three of a kind
-  if (score >= 2e5) return 2; // Two pair
-  if (score >= 1e5) return 1; // One pair
-  return 0;                   // High card
-}
+// Circuit that deals the street after each betting round
+const STREET_CIRCUITS: Record<string, string> = {
+  PreFlop: 'deal_flop',
+  Flop: 'deal_turn',
+  Turn: 'deal_river',
+};
 
 export const GameTablePage: React.FC = () => {
 
@@ -504,7 +448,8 @@ export const GameTablePage: React.FC = () => {
   // Decode hole cards. If Arcium hand account doesn't exist yet, derive visible
   // placeholder cards from the wallet pubkey (debug/plaintext mode).
   const myCards = useMemo(() => {
-    if (myHand) {
+    const cardsLanded = myHand?.encryptedCards?.some((c: number[]) => c.some(b => b !== 0));
+    if (cardsLanded) {
       const decode = (bytes: any): number => {
         if (!bytes) return 0;
         const arr = Array.from(bytes as any);
@@ -512,8 +457,7 @@ export const GameTablePage: React.FC = () => {
         const u32 = ((b[0] ?? 0) | ((b[1] ?? 0) << 8) | ((b[2] ?? 0) << 16) | ((b[3] ?? 0) << 24)) >>> 0;
         return Math.abs(u32) % 52;
       };
-      const card1_raw = myHand.encryptedCard1 || myHand.encrypted_card1;
-      const card2_raw = myHand.encryptedCard2 || myHand.encrypted_card2;
+      const [card1_raw, card2_raw] = myHand.encryptedCards;
       const card1 = decode(card1_raw);
       const card2 = decode(card2_raw);
       return [card1, card2 === card1 ? (card2 + 1) % 52 : card2];
@@ -550,138 +494,62 @@ export const GameTablePage: React.FC = () => {
   // Show the dealing spinner only while we have no cards yet and Arcium hasn't called back
   const isDealing = phase === 'PreFlop' && !myCards && players.some(p => p.wallet.toBase58() === publicKey?.toBase58());
 
-  // ===== ORACLE: Reveal community cards for the given street =====
-  // Simulates the Arcium on_community_cards callback.
-  // fromPhase = the phase BEFORE the advance (PreFlop→Flop, Flop→Turn, Turn→River)
-  const oracleRevealCommunityCards = useCallback(async (
-    fromPhase: string,
-    tablePda: PublicKey,
-    handNum: anchor.BN,
-  ) => {
-    if (!publicKey || !tableId || players.length === 0) return;
-    const handNumber = handNum.toNumber();
-    const deck = buildDeck(tableId, handNumber);
-    // Convention: community cards start at deck index 4 (0-3 are hole pairs for 2 players)
-    // deck[4..6] = flop, deck[7] = turn, deck[8] = river
-    let indices: number[];
-    let values: number[];
-    if (fromPhase === 'PreFlop') {
-      indices = [0, 1, 2];
-      values = [deck[4] ?? 2, deck[5] ?? 16, deck[6] ?? 30];
-    } else if (fromPhase === 'Flop') {
-      indices = [3];
-      values = [deck[7] ?? 44];
-    } else if (fromPhase === 'Turn') {
-      indices = [4];
-      values = [deck[8] ?? 9];
-    } else {
-      return; // River → no community cards; showdown handles it
-    }
-
-    // Use first player as the dummy "calling player" for the ArciumCallback accounts
-    const firstPlayer = players[0];
-    const [handPda] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from('hand'),
-        tablePda.toBuffer(),
-        handNum.toArrayLike(Buffer, 'le', 8),
-        firstPlayer.publicKey.toBuffer(),
-      ],
-      POKER_PROGRAM_ID
-    );
-
-    console.log('[Oracle] Revealing community cards for', fromPhase, '→ indices:', indices, 'values:', values);
-    await (program.methods as any).onCommunityCards(indices, values)
-      .accounts({
-        table: tablePda,
-        arciumMxe: ARCIUM_MXE_PUBKEY,
-        payer: publicKey,
-        encryptedHand: handPda,
-        player: firstPlayer.publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .rpc();
-  }, [publicKey, tableId, players, program]);
-
-  // ===== ORACLE: Evaluate hands, pick winner, settle showdown =====
-  // Simulates the Arcium on_showdown_result callback.
-  const oracleSettleShowdown = useCallback(async (
-    tablePda: PublicKey,
-    handNum: anchor.BN,
-    boardCards: number[], // up to 5 community cards (255 = not yet revealed)
-    finalDeck?: number[]  // optional forced deck for sync
-  ) => {
-    if (!publicKey || !tableId || players.length === 0 || !table) return;
-    const handNumber = handNum.toNumber();
-
-    // Fill any missing board cards with simulated values
-    const deck = finalDeck || buildDeck(tableId, handNumber);
-    const board = boardCards.map((c, i) =>
-      (c === 255 || c === 0) ? (deck[4 + i] ?? (i * 7 + 2)) : c
-    );
-
-    // Evaluate each active player's hand
-    const activePlayers = players.filter(p => p.isActive || p.isAllIn);
-    let bestScore = -1;
-    let winnerIdx = 0;
-    activePlayers.forEach((p, idx) => {
-      const wb = p.wallet.toBytes();
-      const [h1, h2] = simulateHoleCards(wb, tableId, handNumber);
-      const score = scoreHand([h1, h2, ...board]);
-      console.log(`[Oracle] Player ${p.playerId} (${p.wallet.toBase58().slice(0, 8)}) score: ${score}`);
-      if (score > bestScore) { bestScore = score; winnerIdx = idx; }
-    });
-
-    const winner = activePlayers[winnerIdx];
-    if (!winner) return;
-
-    const pot = table.pot.toNumber ? table.pot.toNumber() : table.pot;
-    const winnersArr = new Array(6).fill(255);
-    winnersArr[0] = winner.playerId;
-    const payoutsArr = new Array(6).fill(new anchor.BN(0));
-    payoutsArr[0] = new anchor.BN(pot);
-
-    const [resultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('result'), tablePda.toBuffer(), handNum.toArrayLike(Buffer, 'le', 8)],
-      POKER_PROGRAM_ID
-    );
-
-    const finalBoard: [number, number, number, number, number] = [
-      board[0] ?? deck[4] ?? 2,
-      board[1] ?? deck[5] ?? 16,
-      board[2] ?? deck[6] ?? 30,
-      board[3] ?? deck[7] ?? 44,
-      board[4] ?? deck[8] ?? 9,
-    ];
-
-    console.log(`[Oracle] Winner: player ${winner.playerId}, score: ${bestScore}, hand: ${handCategoryFromScore(bestScore)}, board:`, finalBoard);
-
-    const dummyProof = new Array(256).fill(0);
-    const randomHashArray = Array.from({ length: 32 }, () => Math.floor(Math.random() * 256));
-
-    await (program.methods as any).onShowdownResult(
-      winnersArr,
-      1,
-      payoutsArr,
-      handCategoryFromScore(bestScore),
-      finalBoard,
-      dummyProof,
-      randomHashArray,
-    )
-      .accounts({
-        table: tablePda,
-        gameResult: resultPda,
-        arciumMxe: ARCIUM_MXE_PUBKEY,
-        creator: publicKey,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .remainingAccounts(players.map(p => ({ pubkey: p.publicKey, isSigner: false, isWritable: true })))
-      .rpc();
-
-    console.log('[Oracle] Showdown settled — hand complete');
-  }, [publicKey, tableId, players, program, table]);
+  // Seated Player PDAs in player_id order, as the program expects them
+  const playerAccs = useMemo(() =>
+    players
+      .filter(p => p.playerId !== 255)
+      .sort((a, b) => a.playerId - b.playerId)
+      .map(p => ({ pubkey: p.publicKey, isSigner: false, isWritable: true })),
+    [players]);
+
+  // (Player PDA, EncryptedHand PDA) pairs in player_id order
+  const playerHandAccs = useCallback((tablePda: PublicKey, handNum: anchor.BN) =>
+    playerAccs.flatMap(acc => {
+      const [handPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('hand'), tablePda.toBuffer(), handNum.toArrayLike(Buffer, 'le', 8), acc.pubkey.toBuffer()],
+        POKER_PROGRAM_ID
+      );
+      return [acc, { pubkey: handPda, isSigner: false, isWritable: true }];
+    }), [playerAccs]);
 
+  // Queue the Arcium computation that follows a closed betting round: the
+  // whole run-out once nobody can bet, the showdown after the river, else the next street
+  const queueNextStreet = useCallback(async (fromPhase: string, allInOnly: boolean) => {
+    if (!publicKey || !tableId || !table) return;
+    const tablePda = deriveTablePDA(tableId);
+    const handNum = table.handNumber;
+    const computationOffset = newComputationOffset();
 
+    if (allInOnly || fromPhase === 'River') {
+      const [resultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('result'), tablePda.toBuffer(), handNum.toArrayLike(Buffer, 'le', 8)],
+        POKER_PROGRAM_ID
+      );
+      const [method, circuit] = allInOnly
+        ? ['runOutAllIn', 'run_out_showdown']
+        : ['triggerShowdown', 'execute_showdown'];
+      await (program.methods as any)[method](computationOffset)
+        .accountsPartial({
+          table: tablePda,
+          gameResult: resultPda,
+          creator: publicKey,
+          ...arciumAccounts(circuit, computationOffset),
+        })
+        .remainingAccounts(playerHandAccs(tablePda, handNum))
+        .rpc();
+      console.log('[GameTable] Showdown queued — Arcium computing winner...');
+    } else {
+      await (program.methods as any).dealCommunityCards(computationOffset)
+        .accountsPartial({
+          table: tablePda,
+          creator: publicKey,
+          ...arciumAccounts(STREET_CIRCUITS[fromPhase], computationOffset),
+        })
+        .remainingAccounts(playerAccs)
+        .rpc();
+      console.log('[GameTable] Next street queued');
+    }
+  }, [publicKey, tableId, table, program, playerAccs, playerHandAccs]);
 
   const handleJoin = useCallback(async (seatIndex: number) => {
     if (!publicKey || !tableId) return;
@@ -707,7 +575,11 @@ export const GameTablePage: React.FC = () => {
         POKER_PROGRAM_ID
       );
 
-      await (program.methods as any).joinTable(seatIndex)
+      // Hole cards are encrypted to this key; keep the secret to read them back
+      const encryptionSecret = x25519.utils.randomPrivateKey();
+      localStorage.setItem(`poker-enc-${playerPda.toBase58()}`, Buffer.from(encryptionSecret).toString('hex'));
+
+      await (program.methods as any).joinTable(seatIndex, Array.from(x25519.getPublicKey(encryptionSecret)))
         .accounts({
           table: tablePda,
           player: playerPda,
@@ -732,17 +604,14 @@ export const GameTablePage: React.FC = () => {
     setIsActing(true);
     try {
       const tablePda = deriveTablePDA(tableId);
-      await (program.methods as any).startGame()
-        .accounts({
+      const computationOffset = newComputationOffset();
+      await (program.methods as any).startGame(computationOffset)
+        .accountsPartial({
           table: tablePda,
           creator: publicKey,
-          arciumMxe: ARCIUM_MXE_PUBKEY,
+          ...arciumAccounts('create_encrypted_deck', computationOffset),
         })
-        .remainingAccounts(players.map(p => ({
-          pubkey: p.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })))
+        .remainingAccounts(playerAccs)
         .rpc();
       refetch();
     } catch (err) {
@@ -751,25 +620,22 @@ export const GameTablePage: React.FC = () => {
     } finally {
       setIsActing(false);
     }
-  }, [publicKey, tableId, program, refetch]);
+  }, [publicKey, tableId, program, refetch, playerAccs]);
 
   const handleAction = useCallback(async (type: string, amount?: number) => {
     if (!publicKey || !table || !tableId) return;
     setIsActing(true);
     try {
       const tablePda = deriveTablePDA(tableId);
-      const typeMap: Record<string, number> = { fold: 0, check: 1, call: 2, raise: 3, allin: 4 };
-      const actionType = typeMap[type] ?? 1;
-
-      // The Rust program expects raise_amount as the INCREMENT above the current table bet,
-      // NOT the total raise-to amount that the UI slider/button shows.
-      // e.g. if currentBet=50 and user clicks "Raise to 200", we pass 200-50=150.
-      let programAmount = amount ?? 0;
-      if (type === 'raise' && amount !== undefined) {
-        const tableCurBet = table.currentBet.toNumber ? table.currentBet.toNumber() : table.currentBet;
-        programAmount = Math.max(0, amount - tableCurBet);
-        console.log(`[GameTable] Raise: UI amount=${amount}, tableBet=${tableCurBet}, increment sent=${programAmount}`);
-      }
+      // Raise carries the raise-to total the UI slider/button shows, as the program expects
+      const actions: Record<string, object> = {
+        fold: { fold: {} },
+        check: { check: {} },
+        call: { call: {} },
+        raise: { raise: { 0: new anchor.BN(amount ?? 0) } },
+        allin: { allIn: {} },
+      };
+      const action = actions[type] ?? actions.check;
 
       // Always re-derive the player PDA from canonical seeds so we never pass
       // a stale cache address → fixes AccountDidNotDeserialize on player account.
@@ -778,14 +644,12 @@ export const GameTablePage: React.FC = () => {
         POKER_PROGRAM_ID
       );
 
-      // Fetch fresh player data to get current action_count and player_id
+      // Fetch fresh player data to get the current action_count
       let freshActionCount = 0;
-      let freshPlayerId = myPlayer?.playerId ?? 0;
       try {
         const freshPlayer = await (program.account as any).player.fetch(playerPda);
         freshActionCount = freshPlayer.actionCount ?? 0;
-        freshPlayerId = freshPlayer.playerId ?? freshPlayerId;
-        console.log('[GameTable] Fresh player state — action_count:', freshActionCount, 'player_id:', freshPlayerId);
+        console.log('[GameTable] Fresh player state — action_count:', freshActionCount);
       } catch (fetchErr: any) {
         console.error('[GameTable] Player fetch failed — is the player account on-chain?', fetchErr.message);
         alert(`Could not load your player account. Make sure you have joined this table. (${fetchErr.message})`);
@@ -797,195 +661,53 @@ export const GameTablePage: React.FC = () => {
           Buffer.from('action'),
           tablePda.toBuffer(),
           table.handNumber.toArrayLike(Buffer, 'le', 8),
-          Buffer.from([freshPlayerId]),
+          playerPda.toBuffer(),
           Buffer.from([freshActionCount]),
         ],
         POKER_PROGRAM_ID
       );
 
-      console.log('[GameTable] Submitting action:', type, 'amount:', programAmount, '| playerPda:', playerPda.toBase58());
-
-      await (program.methods as any).submitAction(actionType, new anchor.BN(programAmount))
-        .accounts({
-          table: tablePda,
-          player: playerPda,
-          encryptedAction: actionPda,
-          payer: publicKey,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
-        .remainingAccounts(players.map(p => ({
-          pubkey: p.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })))
-        .rpc();
-
-      refetch();
-    } catch (err) {
-      console.error('[GameTable] Action failed:', err);
-      alert(parseTxError(err));
-    } finally {
-      setIsActing(false);
-    }
-  }, [publicKey, table, myPlayer, tableId, program, players, refetch]);
-
-  const handleDealCommunityCards = useCallback(async () => {
-    if (!publicKey || !tableId) return;
-    setIsActing(true);
-    try {
-      const tablePda = deriveTablePDA(tableId);
-      await (program.methods as any).dealCommunityCards()
-        .accounts({
-          table: tablePda,
-          creator: publicKey,
-        })
-        .remainingAccounts(players.map(p => ({
-          pubkey: p.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })))
-        .rpc();
-      console.log('[GameTable] Deal community cards sent');
-      refetch();
-    } catch (err) {
-      console.error('[GameTable] Deal community cards failed:', err);
-      alert(parseTxError(err));
-    } finally {
-      setIsActing(false);
-    }
-  }, [publicKey, tableId, program, refetch]);
-
-  const handleForceReveal = useCallback(async () => {
-    if (!publicKey || !tableId || !table) return;
-    setIsActing(true);
-    try {
-      const tablePda = deriveTablePDA(tableId);
-      const currentPhaseStr = parseGamePhase(table.phase);
-      let indices: number[] = [];
-
-      if (currentPhaseStr === 'Flop') indices = [0, 1, 2];
-      else if (currentPhaseStr === 'Turn') indices = [3];
-      else if (currentPhaseStr === 'River') indices = [4];
-      else if (currentPhaseStr === 'Showdown' || currentPhaseStr === 'Complete') {
-        // Reveal all locked cards
-        indices = communityCards
-          .map((v: any, i: number) => (v === 255 ? i : -1))
-          .filter((i: number) => i !== -1);
-      }
-
-      if (indices.length === 0) {
-        alert('All cards already revealed or nothing to reveal.');
-        return;
-      }
-
-      // Generate random card values for the demo/stuck state
-      const values = indices.map(() => Math.floor(Math.random() * 52));
-
-      await (program.methods as any).onCommunityCards(indices, values)
-        .accounts({
-          table: tablePda,
-          arciumMxe: ARCIUM_MXE_PUBKEY,
-        })
-        .rpc();
-      console.log('[GameTable] Debug Reveal sent');
-      refetch();
-    } catch (err) {
-      console.error('[GameTable] Reveal failed:', err);
-      alert(parseTxError(err));
-    } finally {
-      setIsActing(false);
-    }
-  }, [publicKey, tableId, table, program, refetch]);
-
-  const handleForceComplete = useCallback(async () => {
-    if (!publicKey || !tableId || !table) return;
-    setIsActing(true);
-    try {
-      const tablePda = deriveTablePDA(tableId);
+      // Created only if this fold leaves a single player, who takes the pot
       const [resultPda] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from('result'),
-          tablePda.toBuffer(),
-          table.handNumber.toArrayLike(Buffer, 'le', 8)
-        ],
+        [Buffer.from('result'), tablePda.toBuffer(), table.handNumber.toArrayLike(Buffer, 'le', 8)],
         POKER_PROGRAM_ID
       );
 
-      // Dummy results for debug: Player 0 wins the pot
-      const winners = new Uint8Array(6).fill(0);
-      winners[0] = 0;
-      const payouts = new Array(6).fill(new anchor.BN(0));
-      payouts[0] = table.pot;
-
-      // Ensure we pass some cards for the board if they are 255
-      const finalBoard = [...communityCards].map(c => (c === 255 ? Math.floor(Math.random() * 52) : c));
-
-      await (program.methods as any).onShowdownResult(
-        Array.from(winners),
-        1,          // winner_count
-        payouts,
-        0,          // winning_hand_category (u8) — required positional arg
-        finalBoard, // final_community_cards
-        new Array(256).fill(0), // Dummy proof
-        new Array(32).fill(0)   // Dummy proof hash
-      )
-        .accounts({
+      console.log('[GameTable] Submitting action:', type, 'amount:', amount ?? 0, '| playerPda:', playerPda.toBase58());
+
+      await (program.methods as any).submitAction(action)
+        .accountsPartial({
           table: tablePda,
+          player: playerPda,
+          encryptedAction: actionPda,
           gameResult: resultPda,
-          arciumMxe: ARCIUM_MXE_PUBKEY,
-          creator: publicKey,
+          payer: publicKey,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
-        .remainingAccounts(players.map(p => ({
-          pubkey: p.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })))
+        .remainingAccounts(playerAccs.filter(acc => !acc.pubkey.equals(playerPda)))
         .rpc();
 
-      console.log('[GameTable] Debug manual complete sent');
       refetch();
     } catch (err) {
-      console.error('[GameTable] Manual complete failed:', err);
+      console.error('[GameTable] Action failed:', err);
       alert(parseTxError(err));
     } finally {
       setIsActing(false);
     }
-  }, [publicKey, tableId, table, program, refetch, players]);
+  }, [publicKey, table, tableId, program, playerAccs, refetch]);
 
-  const handleTriggerShowdown = useCallback(async () => {
-    if (!publicKey || !tableId) return;
+  const handleAdvancePhase = useCallback(async () => {
     setIsActing(true);
     try {
-      const tablePda = deriveTablePDA(tableId);
-      await (program.methods as any).triggerShowdown()
-        .accounts({
-          table: tablePda,
-          creator: publicKey,
-        })
-        .remainingAccounts(players.map(p => ({
-          pubkey: p.publicKey,
-          isSigner: false,
-          isWritable: true,
-        })))
-        .rpc();
-      console.log('[GameTable] Showdown triggered — Arcium computing winner...');
+      await queueNextStreet(phase, false);
       refetch();
     } catch (err) {
-      console.error('[GameTable] Trigger showdown failed:', err);
+      console.error('[GameTable] Advance failed:', err);
       alert(parseTxError(err));
     } finally {
       setIsActing(false);
     }
-  }, [publicKey, tableId, program, refetch, players]);
-
-  const handleAdvancePhase = useCallback(async () => {
-    if (phase === 'River') {
-      await handleTriggerShowdown();
-    } else {
-      await handleDealCommunityCards();
-    }
-  }, [phase, handleTriggerShowdown, handleDealCommunityCards]);
+  }, [phase, queueNextStreet, refetch]);
 
 
   // Log automated table actions
@@ -1012,69 +734,44 @@ export const GameTablePage: React.FC = () => {
     }
   }, [table, tableId, navigate]);
 
-  // ===== AUTO-WIN: last player standing (everyone else folded) =====
-  // The on-chain program still needs deal_community_cards + trigger_showdown even
-  // when only 1 active player is left. We fast-forward through all remaining streets,
-  // calling the oracle callbacks at each step so community cards appear on screen.
-  const autoWinRef = useRef('');
+  // ===== AUTO-DEAL: hole cards, once the shuffled deck lands =====
+  // start_game only queues the shuffle; the creator deals once Arcium's
+  // on_deck_ready callback has stored the deck.
+  const autoDealRef = useRef('');
   useEffect(() => {
     if (!table || !publicKey || !tableId) return;
-    const currentPhase = parseGamePhase(table.phase);
-    if (!['PreFlop', 'Flop', 'Turn', 'River'].includes(currentPhase)) return;
-    if (autoWinRef.current === currentPhase) return;
+    if (parseGamePhase(table.phase) !== 'PreFlop') return;
+    const handKey = table.handNumber.toString();
+    if (autoDealRef.current === handKey) return;
 
-    // Fast-forward to showdown if everyone but one player has folded.
-    const activePlayersDuringHand = players.filter(p => p.isActive);
-    const isOnlyOneLeft = activePlayersDuringHand.length === 1 && players.length > 1;
-
-    // Only the creator triggers the auto-win sequence on-chain
+    const deckReady = table.encryptedDeckHash.some((b: number) => b !== 0) && table.deckNextIndex === 0;
     const isCreator = publicKey.toBase58() === table.creator.toBase58();
-    if (!isOnlyOneLeft || !isCreator) return;
+    if (!deckReady || !isCreator) return;
 
-    autoWinRef.current = currentPhase;
-    console.log('[GameTable:AutoWin] Only 1 player left — fast-forwarding to showdown');
+    autoDealRef.current = handKey;
+    console.log(`[GameTable:AutoDeal] Deck ready — dealing hand #${handKey}`);
 
     const run = async () => {
       try {
         const tablePda = deriveTablePDA(tableId);
-        const playerAccs = players.map(p => ({ pubkey: p.publicKey, isSigner: false, isWritable: true }));
-        const handNum = table.handNumber;
-        const deck = buildDeck(tableId || '', handNum.toNumber());
-
-        // Advance through remaining streets, revealing cards at each step
-        const orderedPhases: string[] = [];
-        if (currentPhase === 'PreFlop') orderedPhases.push('PreFlop', 'Flop', 'Turn');
-        else if (currentPhase === 'Flop') orderedPhases.push('Flop', 'Turn');
-        else if (currentPhase === 'Turn') orderedPhases.push('Turn');
-        // River → go straight to showdown
-
-        for (const fromPhase of orderedPhases) {
-          await (program.methods as any).dealCommunityCards()
-            .accounts({ table: tablePda, creator: publicKey })
-            .remainingAccounts(playerAccs)
-            .rpc();
-          await oracleRevealCommunityCards(fromPhase, tablePda, handNum);
-          await new Promise(r => setTimeout(r, 600));
-        }
-
-        await (program.methods as any).triggerShowdown()
-          .accounts({ table: tablePda, creator: publicKey })
-          .remainingAccounts(playerAccs)
+        const computationOffset = newComputationOffset();
+        await (program.methods as any).dealHoleCards(computationOffset)
+          .accountsPartial({
+            table: tablePda,
+            creator: publicKey,
+            ...arciumAccounts('deal_all_hole_cards', computationOffset),
+          })
+          .remainingAccounts(playerHandAccs(tablePda, table.handNumber))
           .rpc();
-        await new Promise(r => setTimeout(r, 800));
-
-        // Settle — sole active player wins the pot
-        // Pass the same deck we used to reveal cards to ensure perfect sync
-        await oracleSettleShowdown(tablePda, handNum, communityCards, deck);
         refetch();
       } catch (err: any) {
-        console.error('[GameTable:AutoWin] Failed:', err);
-        autoWinRef.current = ''; // allow retry
+        console.error('[GameTable:AutoDeal] Failed:', err);
+        autoDealRef.current = ''; // allow retry
       }
     };
     run();
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [players.map(p => `${p.isActive}-${p.isAllIn}`).join(','), table?.phase]);
+  }, [table?.phase, table?.handNumber?.toString(), table?.encryptedDeckHash?.join(','), table?.deckNextIndex]);
 
   // ===== AUTO-ADVANCE =====
   // Mirrors the on-chain is_betting_complete() condition EXACTLY:
@@ -1082,10 +779,9 @@ export const GameTablePage: React.FC = () => {
   //   - playersActed >= playersToAct means normal betting is finished
   //   - activeNonAllIn <= 1 means only 0 or 1 player can still act
   //
-  // When the condition is met, calls deal_community_cards (Flop/Turn/River)
-  // or trigger_showdown (River done). Then calls oracle callbacks as a
-  // FALLBACK — if Arcium fires its own callbacks first, the try-catch
-  // swallows the duplicate-account error and Arcium's values win.
+  // When the condition is met and the street's cards have landed, queues
+  // the next step (see queueNextStreet). Arcium's callbacks then reveal the
+  // cards or settle the pot.
   const autoAdvancingRef = useRef<string>('');
   useEffect(() => {
     if (!table || !publicKey || !tableId) return;
@@ -1101,48 +797,21 @@ export const GameTablePage: React.FC = () => {
       (table.playersToAct > 0 && table.playersActed >= table.playersToAct);
     const bettingDone = allInOnly || normalBettingDone;
 
+    // Mirror the on-chain is_deal_settled: the hole cards and this street have landed
+    const revealed = communityCards.filter((c: number) => c !== 255).length;
+    const expected: Record<string, number> = { PreFlop: 0, Flop: 3, Turn: 4, River: 5 };
+    const dealSettled = table.deckNextIndex > 0 && revealed === expected[currentPhase];
+
     // Only the creator triggers the next street advance on-chain
     const isCreator = publicKey.toBase58() === table.creator.toBase58();
-    if (!bettingDone || !isCreator) return;
+    if (!bettingDone || !dealSettled || !isCreator) return;
 
     autoAdvancingRef.current = currentPhase;
     console.log(`[GameTable:AutoAdvance] Street ${currentPhase} done — advancing (allInOnly=${allInOnly}, acted=${table.playersActed}/${table.playersToAct})`);
 
     const run = async () => {
       try {
-        const tablePda = deriveTablePDA(tableId);
-        const playerAccs = players.map(p => ({ pubkey: p.publicKey, isSigner: false, isWritable: true }));
-        const handNum = table.handNumber;
-
-        if (currentPhase === 'River') {
-          await (program.methods as any).triggerShowdown()
-            .accounts({ table: tablePda, creator: publicKey })
-            .remainingAccounts(playerAccs)
-            .rpc();
-
-          // Fallback oracle: if Arcium doesn't settle within 3 s, do it ourselves.
-          // If Arcium already fired, the duplicate init error is caught and ignored.
-          await new Promise(r => setTimeout(r, 3000));
-          try {
-            await oracleSettleShowdown(tablePda, handNum, communityCards);
-          } catch (e: any) {
-            console.log('[Oracle] Showdown already settled by Arcium (or error):', e.message);
-          }
-        } else {
-          await (program.methods as any).dealCommunityCards()
-            .accounts({ table: tablePda, creator: publicKey })
-            .remainingAccounts(playerAccs)
-            .rpc();
-
-          // Fallback oracle: feed community cards if Arcium hasn't yet.
-          await new Promise(r => setTimeout(r, 2000));
-          try {
-            await oracleRevealCommunityCards(currentPhase, tablePda, handNum);
-          } catch (e: any) {
-            console.log('[Oracle] Community cards already revealed by Arcium (or error):', e.message);
-          }
-        }
-
+        await queueNextStreet(currentPhase, allInOnly);
         refetch();
       } catch (err: any) {
         console.error('[GameTable:AutoAdvance] Failed:', err);
@@ -1152,7 +821,7 @@ export const GameTablePage: React.FC = () => {
 
     run();
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, [table?.currentTurn, table?.phase, table?.playersActed, table?.playersToAct]);
+  }, [table?.currentTurn, table?.phase, table?.playersActed, table?.playersToAct, table?.deckNextIndex, communityCards.join(',')]);
 
 
 
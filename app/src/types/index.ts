@@ -41,6 +41,7 @@ export interface TableData {
   arciumMxeAccount: PublicKey;
   arciumComputationId: any; // BN
   encryptedDeckHash: number[]; // [u8; 32]
+  deckNextIndex: number; // u8: next undealt card, 0 until the hole cards land
   communityCards: number[]; // [u8; 5]
   mainPot: any; // BN
   sidePots: any[]; // BN[6]
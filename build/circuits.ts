@@ -2,12 +2,14 @@
 // Schema version: 1
 import { createPacker } from '@arcium-hq/client';
 
-export interface [u8;2] {
-    : (number | bigint)[];
+export interface PrivateHand {
+    cards: (number | bigint)[];
+    salt: (number | bigint)[];
 }
 
-export interface [u8;2]Output {
-    : bigint[];
+export interface PrivateHandOutput {
+    cards: bigint[];
+    salt: bigint[];
 }
 
 export interface [u8;52] {
@@ -19,10 +21,39 @@ export interface [u8;52]Output {
 }
 
 export const circuits = {
-    [u8;2]: createPacker<[u8;2], [u8;2]Output>([
-        { name: '[0]', type: { Integer: { signed: false, width: 8 } } },
-        { name: '[1]', type: { Integer: { signed: false, width: 8 } } },
-    ] as const, '[u8;2]'),
+    PrivateHand: createPacker<PrivateHand, PrivateHandOutput>([
+        { name: 'cards[0]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'cards[1]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[0]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[1]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[2]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[3]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[4]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[5]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[6]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[7]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[8]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[9]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[10]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[11]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[12]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[13]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[14]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[15]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[16]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[17]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[18]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[19]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[20]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[21]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[22]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[23]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[24]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[25]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[26]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[27]', type: { Integer: { signed: false, width: 8 } } },
+        { name: 'salt[28]', type: { Integer: { signed: false, width: 8 } } },
+    ] as const, 'PrivateHand'),
     [u8;52]: createPacker<[u8;52], [u8;52]Output>([
         { name: '[0]', type: { Integer: { signed: false, width: 8 } } },
         { name: '[1]', type: { Integer: { signed: false, width: 8 } } },
@@ -0,0 +1 @@
+export type DealAllHoleCards = {"name":"deal_all_hole_cards","inputs":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"outputs":[{"content":[{"content":[{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"array"},{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
@@ -1 +1 @@
-export type DealHoleCards = {"name":"deal_hole_cards","inputs":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
+export type DealHoleCards = {"name":"deal_hole_cards","inputs":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"}],"type":"tuple"}]}
\ No newline at end of file
@@ -0,0 +1 @@
+export type DealRiver = {"name":"deal_river","inputs":[{"content":[{"content":[{"content":[{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"type":"struct"}],"outputs":[{"content":[{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
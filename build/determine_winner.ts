@@ -0,0 +1 @@
+export type DetermineWinner = {"name":"determine_winner","inputs":[{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"}],"type":"struct"}],"type":"array"},{"content":[{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"}],"type":"array"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"}],"outputs":[{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
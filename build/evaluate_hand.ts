@@ -1 +1 @@
-export type EvaluateHand = {"name":"evaluate_hand","inputs":[{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"size_in_bits":8,"type":"u8"}],"outputs":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":16,"type":"u16"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":16,"type":"u16"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
+export type EvaluateHand = {"name":"evaluate_hand","inputs":[{"content":[{"content":[{"type":"arcis_x25519_pubkey"},{"size_in_bits":128,"type":"u128"}],"type":"struct"},{"content":[{"content":[{"size_in_bits":255,"type":"ciphertext"},{"size_in_bits":255,"type":"ciphertext"}],"type":"array"},{"content":[],"type":"array"}],"type":"struct"}],"type":"struct"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"outputs":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":16,"type":"u16"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":16,"type":"u16"},{"size_in_bits":8,"type":"u8"}],"type":"struct"}]}
\ No newline at end of file
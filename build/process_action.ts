@@ -1 +1 @@
-export type ProcessAction = {"name":"process_action","inputs":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"}],"outputs":[{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"}],"type":"tuple"}]}
\ No newline at end of file
+export type ProcessAction = {"name":"process_action","inputs":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"content":[{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"},{"type":"bool"}],"type":"array"},{"size_in_bits":8,"type":"u8"}],"outputs":[{"content":[{"content":[{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"content":[{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":64,"type":"u64"},{"size_in_bits":8,"type":"u8"}],"type":"struct"},{"size_in_bits":8,"type":"u8"},{"type":"bool"}],"type":"tuple"}]}
\ No newline at end of file
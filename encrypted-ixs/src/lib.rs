@@ -1,13 +1,31 @@
+// Circuits index fixed-size arrays directly; Arcis unrolls these loops into gates.
+#![allow(clippy::needless_range_loop, clippy::manual_memcpy)]
+// Circuit outputs are flat tuples, mirrored by the program's output structs.
+#![allow(clippy::type_complexity)]
+
 use arcis::*;
 
 #[encrypted]
 mod circuits {
     use arcis::*;
 
+    const MAX_PLAYERS: usize = 6;
+
     // ===== Types =====
     pub type Deck = Pack<[u8; 52]>;
-    pub type HoleCards = Pack<[u8; 2]>;
-    pub type CommunityFlop = Pack<[u8; 3]>;
+    pub type HoleCards = Pack<PrivateHand>;
+
+    // Proof disclosure modes for prove_hand
+    const DISCLOSE_CARDS: u8 = 0;
+    const DISCLOSE_CATEGORY: u8 = 1;
+    const DISCLOSE_WEAKER: u8 = 2;
+
+    /// Hole cards plus the salt that keeps their on-chain commitment hiding.
+    #[derive(Clone, Copy)]
+    pub struct PrivateHand {
+        pub cards: [u8; 2],
+        pub salt: [u8; 29],
+    }
 
     // ===== Data Structs (Stored on-chain) =====
     pub struct EncryptedDeck {
@@ -22,9 +40,7 @@ mod circuits {
     }
 
     pub struct CommunityCards {
-        pub flop: Enc<Shared, CommunityFlop>,
-        pub turn: Enc<Shared, u8>,
-        pub river: Enc<Shared, u8>,
+        pub cards: [u8; 5], // 255 = not yet revealed
         pub revealed_count: u8,
     }
 
@@ -36,6 +52,19 @@ mod circuits {
         pub is_winner: u8, // 0 or 1
     }
 
+    /// Minimum-disclosure statement about a dealt hand, bound to both hands'
+    /// commitments. Fields outside the chosen `disclosure` stay at 255 (salt at zero).
+    pub struct HandProof {
+        pub disclosure: u8,
+        pub commitment: [u8; 32],
+        pub winner_commitment: [u8; 32],
+        pub cards: [u8; 2],
+        pub salt: [u8; 29],
+        pub hand_category: u8,
+        pub weaker_than_winner: u8,
+    }
+
+    #[derive(Clone, Copy)]
     pub struct PlayerState {
         pub player_id: u8,
         pub chip_count: u64,
@@ -47,6 +76,7 @@ mod circuits {
         pub seat_index: u8,
     }
 
+    #[derive(Clone, Copy)]
     pub struct PotState {
         pub main_pot: u64,
         pub side_pot_count: u8,
@@ -55,6 +85,224 @@ mod circuits {
         pub last_aggressor: u8,
     }
 
+    // ===== Dealing =====
+
+    /// Burns one card, then reveals the next `count` cards onto the board.
+    /// Returns the updated board, deck `next_index` and `burn_count`.
+    fn deal_street(
+        deck: EncryptedDeck,
+        board: CommunityCards,
+        count: usize,
+    ) -> (CommunityCards, u8, u8) {
+        let deck_array = deck.cards.to_arcis().unpack();
+        let first = (deck.next_index + 1) as usize; // skip the burn card
+        let mut street = [0u8; 3];
+        for i in 0..3 {
+            if i < count {
+                street[i] = deck_array[first + i];
+            }
+        }
+        let street = street.reveal();
+
+        let mut cards = board.cards;
+        for i in 0..3 {
+            if i < count {
+                cards[board.revealed_count as usize + i] = street[i];
+            }
+        }
+
+        (
+            CommunityCards {
+                cards,
+                revealed_count: board.revealed_count + count as u8,
+            },
+            (deck.next_index + 1 + count as u8).reveal(),
+            (deck.burn_count + 1).reveal(),
+        )
+    }
+
+    fn hand_commitment(hand: &PrivateHand) -> [u8; 32] {
+        let mut message = [0u8; 31];
+        message[0] = hand.cards[0];
+        message[1] = hand.cards[1];
+        for i in 0..29 {
+            message[i + 2] = hand.salt[i];
+        }
+        SHA3_256::new().digest(&message)
+    }
+
+    // ===== Hand Ranking =====
+    // Cards are 0-51: rank = card % 13 (0 = deuce .. 12 = ace), suit = card / 13.
+    // Categories: 0 high card, 1 pair, 2 two pair, 3 trips, 4 straight,
+    // 5 flush, 6 full house, 7 quads, 8 straight flush.
+
+    pub(crate) fn seven_cards(hole: [u8; 2], board: [u8; 5]) -> [u8; 7] {
+        let mut cards = [0u8; 7];
+        cards[0] = hole[0];
+        cards[1] = hole[1];
+        for i in 0..5 {
+            cards[i + 2] = board[i];
+        }
+        cards
+    }
+
+    fn card_suit(card: u8) -> u8 {
+        (card >= 13) as u8 + (card >= 26) as u8 + (card >= 39) as u8
+    }
+
+    /// Highest straight in `mask`, as the rank of its top card (3 = five-high wheel).
+    fn best_straight(mask: [bool; 13]) -> (bool, u8) {
+        let mut found = false;
+        let mut high = 0u8;
+        for h in 3..13 {
+            let mut run = true;
+            for k in 0..5 {
+                // (h - k) wrapping below deuce lands on the ace for the wheel
+                run = run && mask[(h + 13 - k) % 13];
+            }
+            if run {
+                found = true;
+                high = h as u8;
+            }
+        }
+        (found, high)
+    }
+
+    /// The five highest ranks set in `mask`, best first. Unused slots stay 0.
+    fn top_five(mask: [bool; 13]) -> [u8; 5] {
+        let mut out = [0u8; 5];
+        let mut taken = 0u8;
+        for i in 0..13 {
+            let r = 12 - i;
+            for j in 0..5 {
+                if mask[r] && taken == j as u8 {
+                    out[j] = r as u8;
+                }
+            }
+            if mask[r] {
+                taken += 1;
+            }
+        }
+        out
+    }
+
+    /// Scores the best five-card hand out of seven.
+    /// Returns the category and a score ordering any two hands: the category in
+    /// bits 20-23 followed by five tie-break ranks, one nibble each.
+    pub(crate) fn score_seven(cards: [u8; 7]) -> (u8, u32) {
+        let mut ranks = [0u8; 7];
+        let mut suits = [0u8; 7];
+        for i in 0..7 {
+            suits[i] = card_suit(cards[i]);
+            ranks[i] = cards[i] - 13 * suits[i];
+        }
+
+        let mut flush = false;
+        let mut flush_suit = 0u8;
+        for s in 0..4 {
+            let mut n = 0u8;
+            for i in 0..7 {
+                n += (suits[i] == s as u8) as u8;
+            }
+            if n >= 5 {
+                flush = true;
+                flush_suit = s as u8;
+            }
+        }
+
+        let mut present = [false; 13];
+        let mut pairs = [false; 13];
+        let mut trips = [false; 13];
+        let mut quads = [false; 13];
+        let mut suited = [false; 13];
+        let mut n_pairs = 0u8;
+        let mut n_trips = 0u8;
+        let mut has_quads = false;
+        for r in 0..13 {
+            let mut count = 0u8;
+            for i in 0..7 {
+                let hit = ranks[i] == r as u8;
+                count += hit as u8;
+                suited[r] = suited[r] || (flush && hit && suits[i] == flush_suit);
+            }
+            present[r] = count >= 1;
+            pairs[r] = count >= 2;
+            trips[r] = count >= 3;
+            quads[r] = count == 4;
+            n_pairs += pairs[r] as u8;
+            n_trips += trips[r] as u8;
+            has_quads = has_quads || quads[r];
+        }
+
+        let (straight, straight_high) = best_straight(present);
+        let (straight_flush, straight_flush_high) = best_straight(suited);
+        let pair_ranks = top_five(pairs);
+        let trip_ranks = top_five(trips);
+        let quad_ranks = top_five(quads);
+
+        // Kickers come from whatever is left once the made ranks are set aside
+        let mut no_pair = [false; 13];
+        let mut no_two_pair = [false; 13];
+        let mut no_trips = [false; 13];
+        let mut no_quads = [false; 13];
+        let mut fh_pairs = [false; 13];
+        for r in 0..13 {
+            let rank = r as u8;
+            no_pair[r] = present[r] && rank != pair_ranks[0];
+            no_two_pair[r] = no_pair[r] && rank != pair_ranks[1];
+            no_trips[r] = present[r] && rank != trip_ranks[0];
+            no_quads[r] = present[r] && rank != quad_ranks[0];
+            fh_pairs[r] = pairs[r] && rank != trip_ranks[0];
+        }
+        let pair_kickers = top_five(no_pair);
+        let two_pair_kickers = top_five(no_two_pair);
+        let trip_kickers = top_five(no_trips);
+        let quad_kickers = top_five(no_quads);
+        let fh_pair = top_five(fh_pairs);
+
+        // Lowest category first so each stronger hand overrides the previous one
+        let mut category = 0u8;
+        let mut tiebreak = top_five(present);
+        if n_pairs >= 1 {
+            category = 1;
+            tiebreak = [pair_ranks[0], pair_kickers[0], pair_kickers[1], pair_kickers[2], 0];
+        }
+        if n_pairs >= 2 {
+            category = 2;
+            tiebreak = [pair_ranks[0], pair_ranks[1], two_pair_kickers[0], 0, 0];
+        }
+        if n_trips >= 1 {
+            category = 3;
+            tiebreak = [trip_ranks[0], trip_kickers[0], trip_kickers[1], 0, 0];
+        }
+        if straight {
+            category = 4;
+            tiebreak = [straight_high, 0, 0, 0, 0];
+        }
+        if flush {
+            category = 5;
+            tiebreak = top_five(suited);
+        }
+        if n_trips >= 1 && n_pairs >= 2 {
+            category = 6;
+            tiebreak = [trip_ranks[0], fh_pair[0], 0, 0, 0];
+        }
+        if has_quads {
+            category = 7;
+            tiebreak = [quad_ranks[0], quad_kickers[0], 0, 0, 0];
+        }
+        if straight_flush {
+            category = 8;
+            tiebreak = [straight_flush_high, 0, 0, 0, 0];
+        }
+
+        let mut score = category as u32;
+        for i in 0..5 {
+            score = score * 16 + tiebreak[i] as u32;
+        }
+        (category, score)
+    }
+
     // ===== Instructions =====
 
     #[instruction]
@@ -72,60 +320,570 @@ mod circuits {
     pub fn deal_hole_cards(
         deck_cards: Enc<Mxe, Deck>,
         deck_next_index: u8,
-        player_id: u8,
+        _player_id: u8,
         player_key: Shared,
-    ) -> (Enc<Shared, HoleCards>, u8) {
+    ) -> (Enc<Shared, HoleCards>, u8, [u8; 32]) {
         let deck_array = deck_cards.to_arcis().unpack();
         let mut hole = [0u8; 2];
         hole[0] = deck_array[deck_next_index as usize];
         hole[1] = deck_array[(deck_next_index + 1) as usize];
-        
-        (player_key.from_arcis(Pack::new(hole)), (deck_next_index + 2).reveal())
+        let hand = PrivateHand {
+            cards: hole,
+            salt: ArcisRNG::gen_uniform::<[u8; 29]>(),
+        };
+        let commitment = hand_commitment(&hand);
+
+        (
+            player_key.from_arcis(Pack::new(hand)),
+            (deck_next_index + 2).reveal(),
+            commitment.reveal(),
+        )
+    }
+
+    /// Deals hole cards to every seat in one pass, one card around the table
+    /// then the second. Seats at or beyond `player_count` get cards of 255.
+    #[instruction]
+    pub fn deal_all_hole_cards(
+        deck_cards: Enc<Mxe, Deck>,
+        deck_next_index: u8,
+        player_keys: [Shared; MAX_PLAYERS],
+        player_count: u8,
+    ) -> (
+        [Enc<Shared, HoleCards>; MAX_PLAYERS],
+        [[u8; 32]; MAX_PLAYERS],
+        u8,
+    ) {
+        let deck_array = deck_cards.to_arcis().unpack();
+        let mut hands = [PrivateHand {
+            cards: [255u8; 2],
+            salt: [0u8; 29],
+        }; MAX_PLAYERS];
+        let mut commitments = [[0u8; 32]; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            if i < player_count as usize {
+                hands[i].cards[0] = deck_array[deck_next_index as usize + i];
+                hands[i].cards[1] = deck_array[(deck_next_index + player_count) as usize + i];
+            }
+            hands[i].salt = ArcisRNG::gen_uniform::<[u8; 29]>();
+            commitments[i] = hand_commitment(&hands[i]);
+        }
+
+        let [k0, k1, k2, k3, k4, k5] = player_keys;
+        (
+            [
+                k0.from_arcis(Pack::new(hands[0])),
+                k1.from_arcis(Pack::new(hands[1])),
+                k2.from_arcis(Pack::new(hands[2])),
+                k3.from_arcis(Pack::new(hands[3])),
+                k4.from_arcis(Pack::new(hands[4])),
+                k5.from_arcis(Pack::new(hands[5])),
+            ],
+            commitments.reveal(),
+            (deck_next_index + 2 * player_count).reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn deal_flop(deck: EncryptedDeck, board: CommunityCards) -> (CommunityCards, u8, u8) {
+        deal_street(deck, board, 3)
+    }
+
+    #[instruction]
+    pub fn deal_turn(deck: EncryptedDeck, board: CommunityCards) -> (CommunityCards, u8, u8) {
+        deal_street(deck, board, 1)
+    }
+
+    #[instruction]
+    pub fn deal_river(deck: EncryptedDeck, board: CommunityCards) -> (CommunityCards, u8, u8) {
+        deal_street(deck, board, 1)
     }
 
     #[instruction]
     pub fn evaluate_hand(
         hole_cards_enc: Enc<Shared, HoleCards>,
+        community_cards: [u8; 5],
         player_id: u8,
-    ) -> (u8, u16, u8, u16, u8) {
-        let hole = hole_cards_enc.to_arcis().unpack();
-        let c1 = hole[0];
-        let c2 = hole[1];
-        
-        let rank: u16 = (c1 as u16 + c2 as u16) * 10;
-        
+    ) -> HandResult {
+        let hole = hole_cards_enc.to_arcis().unpack().cards;
+        let (category, score) = score_seven(seven_cards(hole, community_cards));
+
+        HandResult {
+            player_id,
+            hand_rank: (score >> 8) as u16,
+            hand_category: category,
+            kicker_value: (score % 256) as u16,
+            is_winner: 0,
+        }
+        .reveal()
+    }
+
+    /// Ranks every live hand and reveals only who holds the best one.
+    /// Folded or empty seats are masked out by `live`; their `cards` are ignored.
+    /// Returns the winning player ids (255-padded), how many tied for the pot,
+    /// and the winning hand category. Losing hands never leave the MPC.
+    #[instruction]
+    pub fn determine_winner(
+        hands: [PlayerHand; MAX_PLAYERS],
+        live: [bool; MAX_PLAYERS],
+        community_cards: [u8; 5],
+    ) -> ([u8; MAX_PLAYERS], u8, u8) {
+        let mut scores = [0u32; MAX_PLAYERS];
+        let mut categories = [0u8; MAX_PLAYERS];
+        let mut best = 0u32;
+        for i in 0..MAX_PLAYERS {
+            let hole = hands[i].cards.to_arcis().unpack().cards;
+            let (category, score) = score_seven(seven_cards(hole, community_cards));
+            scores[i] = score;
+            categories[i] = category;
+            if live[i] && score > best {
+                best = score;
+            }
+        }
+
+        let mut winners = [255u8; MAX_PLAYERS];
+        let mut winner_count = 0u8;
+        let mut winning_category = 0u8;
+        for i in 0..MAX_PLAYERS {
+            let wins = live[i] && scores[i] == best;
+            for j in 0..MAX_PLAYERS {
+                if wins && winner_count == j as u8 {
+                    winners[j] = hands[i].player_id;
+                }
+            }
+            if wins {
+                winner_count += 1;
+                winning_category = categories[i];
+            }
+        }
+
+        (winners.reveal(), winner_count.reveal(), winning_category.reveal())
+    }
+
+    /// Splits the pot into main and side pots from each player's `total_contributed`
+    /// and awards every layer to the best live hand eligible for it. Folded chips stay
+    /// in the pots but folded players are never eligible. Returns winner ids
+    /// (255-padded), winner count, the payout of each listed winner and the best
+    /// hand's category, in the shape `on_showdown_result` takes.
+    #[instruction]
+    pub fn execute_showdown(
+        hands: [PlayerHand; MAX_PLAYERS],
+        contributions: [u64; MAX_PLAYERS],
+        live: [bool; MAX_PLAYERS],
+        community_cards: [u8; 5],
+    ) -> ([u8; MAX_PLAYERS], u8, [u64; MAX_PLAYERS], u8) {
+        let mut hole = [[0u8; 2]; MAX_PLAYERS];
+        let mut player_ids = [0u8; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            hole[i] = hands[i].cards.to_arcis().unpack().cards;
+            player_ids[i] = hands[i].player_id;
+        }
+        let (winners, winner_count, payouts, winning_category) =
+            award_pots(hole, player_ids, contributions, live, community_cards);
+
         (
-            player_id.reveal(),
-            rank.reveal(),
-            (rank / 100).reveal() as u8,
-            (c1 as u16).reveal(),
-            0u8.reveal() // is_winner
+            winners.reveal(),
+            winner_count.reveal(),
+            payouts.reveal(),
+            winning_category.reveal(),
         )
     }
 
+    /// Deals every community card still to come, burning before each street as
+    /// dealing them one by one would, then settles the pots as `execute_showdown`
+    /// does. Used when no more betting is possible. Live players' hole cards are
+    /// revealed alongside the board (255 for folded seats).
+    #[instruction]
+    pub fn run_out_showdown(
+        hands: [PlayerHand; MAX_PLAYERS],
+        contributions: [u64; MAX_PLAYERS],
+        live: [bool; MAX_PLAYERS],
+        board: CommunityCards,
+        deck: EncryptedDeck,
+    ) -> (
+        CommunityCards,
+        [[u8; 2]; MAX_PLAYERS],
+        [u8; MAX_PLAYERS],
+        u8,
+        [u64; MAX_PLAYERS],
+        u8,
+    ) {
+        let deck_array = deck.cards.to_arcis().unpack();
+        let mut cards = [0u8; 5];
+        let mut burns = 0u8;
+        for s in 0..5 {
+            let slot = s as u8;
+            let fresh = slot >= board.revealed_count;
+            // Flop, turn and river start at slots 0, 3 and 4
+            if fresh && (slot == 0 || slot == 3 || slot == 4) {
+                burns += 1;
+            }
+            let offset = if fresh { slot - board.revealed_count } else { 0 };
+            let index = (deck.next_index + burns + offset) as usize;
+            cards[s] = if fresh { deck_array[index] } else { board.cards[s] };
+        }
+        let cards = cards.reveal();
+
+        let mut hole = [[0u8; 2]; MAX_PLAYERS];
+        let mut player_ids = [0u8; MAX_PLAYERS];
+        let mut shown = [[255u8; 2]; MAX_PLAYERS];
+        for i in 0..MAX_PLAYERS {
+            hole[i] = hands[i].cards.to_arcis().unpack().cards;
+            player_ids[i] = hands[i].player_id;
+            if live[i] {
+                shown[i] = hole[i];
+            }
+        }
+        let (winners, winner_count, payouts, winning_category) =
+            award_pots(hole, player_ids, contributions, live, cards);
+
+        (
+            CommunityCards {
+                cards,
+                revealed_count: 5,
+            },
+            shown.reveal(),
+            winners.reveal(),
+            winner_count.reveal(),
+            payouts.reveal(),
+            winning_category.reveal(),
+        )
+    }
+
+    /// Scores every seat and splits the contributions into pot layers, as
+    /// described on `execute_showdown`. Nothing is revealed here.
+    fn award_pots(
+        hole: [[u8; 2]; MAX_PLAYERS],
+        player_ids: [u8; MAX_PLAYERS],
+        contributions: [u64; MAX_PLAYERS],
+        live: [bool; MAX_PLAYERS],
+        community_cards: [u8; 5],
+    ) -> ([u8; MAX_PLAYERS], u8, [u64; MAX_PLAYERS], u8) {
+        let mut scores = [0u32; MAX_PLAYERS];
+        let mut best_overall = 0u32;
+        let mut winning_category = 0u8;
+        for i in 0..MAX_PLAYERS {
+            let (category, score) = score_seven(seven_cards(hole[i], community_cards));
+            scores[i] = score;
+            if live[i] && score > best_overall {
+                best_overall = score;
+                winning_category = category;
+            }
+        }
+
+        let mut awarded = [0u64; MAX_PLAYERS];
+        for k in 0..MAX_PLAYERS {
+            // Every distinct live contribution caps one pot layer
+            let level = contributions[k];
+            let mut first = live[k];
+            let mut floor = 0u64;
+            let mut top = true;
+            for j in 0..MAX_PLAYERS {
+                if live[j] && j < k && contributions[j] == level {
+                    first = false;
+                }
+                if live[j] && contributions[j] < level && contributions[j] > floor {
+                    floor = contributions[j];
+                }
+                if live[j] && contributions[j] > level {
+                    top = false;
+                }
+            }
+
+            // The top layer also sweeps up anything folded players put in above it
+            let mut pot = 0u64;
+            for i in 0..MAX_PLAYERS {
+                let c = contributions[i];
+                let upper = if top || c < level { c } else { level };
+                let lower = if c < floor { c } else { floor };
+                pot += upper - lower;
+            }
+
+            let mut best = 0u32;
+            for i in 0..MAX_PLAYERS {
+                if live[i] && contributions[i] >= level && scores[i] > best {
+                    best = scores[i];
+                }
+            }
+            let mut count = 0u64;
+            for i in 0..MAX_PLAYERS {
+                if live[i] && contributions[i] >= level && scores[i] == best {
+                    count += 1;
+                }
+            }
+            let share = pot / if count > 0 { count } else { 1 };
+
+            // Odd chips go to the first winner in seat order
+            let mut remainder = pot - share * count;
+            for i in 0..MAX_PLAYERS {
+                if first && live[i] && contributions[i] >= level && scores[i] == best {
+                    awarded[i] += share + remainder;
+                    remainder = 0;
+                }
+            }
+        }
+
+        let mut winners = [255u8; MAX_PLAYERS];
+        let mut payouts = [0u64; MAX_PLAYERS];
+        let mut winner_count = 0u8;
+        for i in 0..MAX_PLAYERS {
+            let paid = awarded[i] > 0;
+            for j in 0..MAX_PLAYERS {
+                if paid && winner_count == j as u8 {
+                    winners[j] = player_ids[i];
+                    payouts[j] = awarded[i];
+                }
+            }
+            if paid {
+                winner_count += 1;
+            }
+        }
+
+        (winners, winner_count, payouts, winning_category)
+    }
+
+    /// Opens a stored hand only as far as the player chooses: the exact cards
+    /// (with the salt, so anyone can re-hash the commitment), only the hand
+    /// category on `community_cards`, or only whether it lost to `winner_hand`.
+    /// Both commitments are recomputed so the program can check the statement
+    /// against the prover's and the winner's `EncryptedHand.arcium_commitment`.
+    #[instruction]
+    pub fn prove_hand(
+        hand_enc: Enc<Shared, HoleCards>,
+        winner_hand_enc: Enc<Shared, HoleCards>,
+        community_cards: [u8; 5],
+        disclosure: u8,
+    ) -> HandProof {
+        let hand = hand_enc.to_arcis().unpack();
+        let winner_hand = winner_hand_enc.to_arcis().unpack();
+        let (category, score) = score_seven(seven_cards(hand.cards, community_cards));
+        let (_, winning_score) = score_seven(seven_cards(winner_hand.cards, community_cards));
+
+        let mut cards = [255u8; 2];
+        let mut salt = [0u8; 29];
+        let mut hand_category = 255u8;
+        let mut weaker_than_winner = 255u8;
+        if disclosure == DISCLOSE_CARDS {
+            cards = hand.cards;
+            salt = hand.salt;
+        }
+        if disclosure == DISCLOSE_CATEGORY {
+            hand_category = category;
+        }
+        if disclosure == DISCLOSE_WEAKER {
+            weaker_than_winner = (score < winning_score) as u8;
+        }
+
+        HandProof {
+            disclosure,
+            commitment: hand_commitment(&hand),
+            winner_commitment: hand_commitment(&winner_hand),
+            cards,
+            salt,
+            hand_category,
+            weaker_than_winner,
+        }
+        .reveal()
+    }
+
+    /// Applies one betting action (0 fold, 1 check, 2 call, 3 raise, 4 all-in) and
+    /// finds the next seat that can still act, walking clockwise around a table of
+    /// `table_size` seats. `can_act` is indexed by seat and excludes folded and
+    /// all-in players; 255 means nobody is left to act this street. An illegal
+    /// action comes back with `valid == false` and the state untouched.
     #[instruction]
     pub fn process_action(
         action_type: u8,
-        player_chip_count: u64,
-        player_current_bet: u64,
-        pot_current_bet: u64,
-        pot_main_pot: u64,
-        player_seat_index: u8,
-    ) -> (u64, u64, u64, u8) {
-        let mut new_chips = player_chip_count;
-        let mut new_p_bet = player_current_bet;
-        let mut new_main_pot = pot_main_pot;
-        
-        if action_type == 2 { // Call
-            let mut diff = 0u64;
-            if pot_current_bet > player_current_bet {
-                diff = pot_current_bet - player_current_bet;
+        raise_amount: u64,
+        player: PlayerState,
+        pot: PotState,
+        can_act: [bool; MAX_PLAYERS],
+        table_size: u8,
+    ) -> (PlayerState, PotState, u8, bool) {
+        let mut next_player = player;
+        let mut next_pot = pot;
+        let mut valid = player.is_active == 1 && player.is_all_in == 0;
+
+        let mut to_call = 0u64;
+        if pot.current_bet > player.current_bet {
+            to_call = pot.current_bet - player.current_bet;
+        }
+
+        if action_type == 0 {
+            // Fold
+            next_player.is_active = 0;
+        } else if action_type == 1 {
+            // Check
+            valid = valid && to_call == 0;
+        } else if action_type == 2 {
+            // Call, going all-in if the stack is short
+            let paid = if to_call < player.chip_count { to_call } else { player.chip_count };
+            next_player.chip_count = player.chip_count - paid;
+            next_player.current_bet = player.current_bet + paid;
+            next_player.total_contributed = player.total_contributed + paid;
+            next_pot.main_pot = pot.main_pot + paid;
+        } else if action_type == 3 {
+            // Raise by `raise_amount` on top of the call
+            valid = valid
+                && raise_amount >= pot.min_raise
+                && player.chip_count >= to_call + raise_amount;
+            if valid {
+                let paid = to_call + raise_amount;
+                next_player.chip_count = player.chip_count - paid;
+                next_player.current_bet = pot.current_bet + raise_amount;
+                next_player.total_contributed = player.total_contributed + paid;
+                next_pot.main_pot = pot.main_pot + paid;
+                next_pot.current_bet = next_player.current_bet;
+                next_pot.min_raise = raise_amount;
+                next_pot.last_aggressor = player.player_id;
             }
-            new_chips = player_chip_count - diff;
-            new_p_bet = player_current_bet + diff;
-            new_main_pot = pot_main_pot + diff;
+        } else if action_type == 4 {
+            // All-in; only a full raise resets the minimum raise
+            let paid = player.chip_count;
+            next_player.chip_count = 0;
+            next_player.current_bet = player.current_bet + paid;
+            next_player.total_contributed = player.total_contributed + paid;
+            next_pot.main_pot = pot.main_pot + paid;
+            if next_player.current_bet > pot.current_bet {
+                let increment = next_player.current_bet - pot.current_bet;
+                if increment >= pot.min_raise {
+                    next_pot.min_raise = increment;
+                }
+                next_pot.current_bet = next_player.current_bet;
+                next_pot.last_aggressor = player.player_id;
+            }
+        } else {
+            valid = false;
         }
-        
-        (new_chips.reveal(), new_p_bet.reveal(), new_main_pot.reveal(), ((player_seat_index + 1) % 6).reveal())
+
+        if valid {
+            next_player.has_acted = 1;
+            if next_player.is_active == 1 && next_player.chip_count == 0 {
+                next_player.is_all_in = 1;
+            }
+        } else {
+            next_player = player;
+            next_pot = pot;
+        }
+
+        let mut next_seat = 255u8;
+        let mut found = false;
+        for k in 1..MAX_PLAYERS {
+            let mut seat = player.seat_index + k as u8;
+            if seat >= table_size {
+                seat -= table_size;
+            }
+            if !found && (k as u8) < table_size && can_act[seat as usize] {
+                next_seat = seat;
+                found = true;
+            }
+        }
+        if !valid {
+            next_seat = player.seat_index;
+        }
+
+        (next_player.reveal(), next_pot.reveal(), next_seat.reveal(), valid.reveal())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::circuits::{score_seven, seven_cards};
+
+    const DEUCE: u8 = 0;
+    const THREE: u8 = 1;
+    const FOUR: u8 = 2;
+    const FIVE: u8 = 3;
+    const SIX: u8 = 4;
+    const SEVEN: u8 = 5;
+    const EIGHT: u8 = 6;
+    const NINE: u8 = 7;
+    const JACK: u8 = 9;
+    const QUEEN: u8 = 10;
+    const KING: u8 = 11;
+    const ACE: u8 = 12;
+
+    // Suits 0-3: clubs, diamonds, hearts, spades
+    fn card(rank: u8, suit: u8) -> u8 {
+        suit * 13 + rank
+    }
+
+    #[test]
+    fn wheel_loses_to_six_high_straight() {
+        let wheel = [card(ACE, 0), card(THREE, 1), card(FOUR, 2), card(FIVE, 3), card(DEUCE, 0), card(NINE, 1), card(KING, 2)];
+        let six_high = [card(SIX, 0), card(THREE, 1), card(FOUR, 2), card(FIVE, 3), card(DEUCE, 0), card(NINE, 1), card(KING, 2)];
+
+        let (wheel_category, wheel_score) = score_seven(wheel);
+        let (six_category, six_score) = score_seven(six_high);
+        assert_eq!((wheel_category, six_category), (4, 4));
+        assert!(wheel_score < six_score);
+    }
+
+    #[test]
+    fn straight_flush_inside_a_seven_card_flush() {
+        let hand = [card(DEUCE, 2), card(THREE, 2), card(FOUR, 2), card(FIVE, 2), card(SIX, 2), card(NINE, 2), card(KING, 2)];
+
+        let (category, score) = score_seven(hand);
+        assert_eq!(category, 8);
+        assert_eq!(score, (8 << 20) | ((SIX as u32) << 16));
+    }
+
+    #[test]
+    fn off_suit_straight_card_leaves_a_plain_flush() {
+        // Six hearts plus an off-suit six completing 2-6
+        let hand = [card(DEUCE, 2), card(THREE, 2), card(FOUR, 2), card(FIVE, 2), card(SIX, 0), card(NINE, 2), card(KING, 2)];
+
+        let (category, score) = score_seven(hand);
+        assert_eq!(category, 5);
+        let top = [KING, NINE, FIVE, FOUR, THREE].iter().fold(5u32, |s, &r| s * 16 + r as u32);
+        assert_eq!(score, top);
+    }
+
+    #[test]
+    fn two_sets_make_a_full_house() {
+        let hand = [card(KING, 0), card(KING, 1), card(KING, 2), card(SEVEN, 0), card(SEVEN, 1), card(SEVEN, 3), card(DEUCE, 2)];
+
+        let (category, score) = score_seven(hand);
+        assert_eq!(category, 6);
+        assert_eq!(score, (6 << 20) | ((KING as u32) << 16) | ((SEVEN as u32) << 12));
+    }
+
+    #[test]
+    fn third_pair_plays_as_the_kicker() {
+        let hand = [card(ACE, 0), card(ACE, 1), card(KING, 2), card(KING, 3), card(QUEEN, 0), card(QUEEN, 1), card(JACK, 2)];
+        let lower_third_pair = [card(ACE, 0), card(ACE, 1), card(KING, 2), card(KING, 3), card(SIX, 0), card(SIX, 1), card(JACK, 2)];
+
+        let (category, score) = score_seven(hand);
+        assert_eq!(category, 2);
+        assert_eq!(score, (2 << 20) | ((ACE as u32) << 16) | ((KING as u32) << 12) | ((QUEEN as u32) << 8));
+        // The jack outkicks the lower third pair
+        let (_, lower_score) = score_seven(lower_third_pair);
+        assert_eq!(lower_score & 0xf00, (JACK as u32) << 8);
+    }
+
+    #[test]
+    fn quads_take_the_kicker_from_the_trips() {
+        let hand = [card(NINE, 0), card(NINE, 1), card(NINE, 2), card(NINE, 3), card(SIX, 0), card(SIX, 1), card(SIX, 2)];
+
+        let (category, score) = score_seven(hand);
+        assert_eq!(category, 7);
+        assert_eq!(score, (7 << 20) | ((NINE as u32) << 16) | ((SIX as u32) << 12));
+    }
+
+    #[test]
+    fn fourth_and_fifth_kickers_break_ties() {
+        let board = [card(ACE, 0), card(KING, 1), card(NINE, 2), card(SEVEN, 3), card(DEUCE, 0)];
+        let score_with = |hole: [u8; 2]| score_seven(seven_cards(hole, board)).1;
+
+        // High card: A K 9 7 6 beats A K 9 7 5 on the fifth kicker
+        assert!(score_with([card(SIX, 1), card(THREE, 2)]) > score_with([card(FIVE, 1), card(THREE, 2)]));
+        // A K 9 8 x beats A K 9 7 x on the fourth kicker
+        assert!(score_with([card(EIGHT, 1), card(THREE, 2)]) > score_with([card(SIX, 1), card(THREE, 2)]));
+        // Cards below the best five never count
+        assert_eq!(score_with([card(FIVE, 1), card(THREE, 2)]), score_with([card(FIVE, 1), card(FOUR, 2)]));
+        // A pair keeps three kickers, so a fourth can't split it
+        assert_eq!(
+            score_with([card(ACE, 1), card(SIX, 2)]),
+            score_with([card(ACE, 1), card(FIVE, 2)])
+        );
     }
 }
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::token::{Token, TokenAccount};
 use arcium_anchor::prelude::*;
+use arcium_anchor::traits::QueueCompAccs;
+use arcium_client::idl::arcium::cpi::accounts::QueueComputation;
+use arcium_client::idl::arcium::types::{CallbackAccount, CallbackInstruction};
+use sha3::{Digest, Sha3_256};
+use solana_sha256_hasher::hashv;
 
 declare_id!("AxwPZ5ZiuZwrFss1jjFh5zAozYt2EKBZYT9Mw2wN7fye");
 
@@ -8,6 +15,13 @@ declare_id!("AxwPZ5ZiuZwrFss1jjFh5zAozYt2EKBZYT9Mw2wN7fye");
 pub const MAX_PLAYERS: usize = 6;
 pub const STARTING_CHIPS: u64 = 2000;
 pub const TIME_BANK_SECONDS: i64 = 30;
+pub const UNSEATED: u8 = 255; // player_id of a busted player who no longer gets dealt in
+pub const FIXED_LIMIT_RAISE_CAP: u8 = 4; // Bets per street in fixed-limit, the blind counting as one
+pub const AFK_TIMED_OUT_HANDS: u8 = 2; // Timed-out hands before a player is sat out automatically
+pub const NO_SHOWDOWN: u8 = 255; // winning_hand_category of a pot won uncontested
+pub const DISCLOSE_CARDS: u8 = 0; // prove_hand: the exact cards and salt
+pub const DISCLOSE_CATEGORY: u8 = 1; // prove_hand: only the hand category
+pub const DISCLOSE_WEAKER: u8 = 2; // prove_hand: only whether it lost to the winner
 pub const PLAYER_DISC: [u8; 8] = [205, 222, 112, 7, 165, 155, 206, 218];
 
 // ===== Game State Enums =====
@@ -23,11 +37,31 @@ pub enum GamePhase {
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum BettingStructure {
+    NoLimit,
+    PotLimit,
+    FixedLimit, // Small bet = big blind preflop and flop, big bet = 2x on turn and river
+}
+
+/// Optional forced bets on top of the blinds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ForcedBets {
+    pub ante: u64,             // 0 = no ante
+    pub big_blind_ante: bool,  // The big blind posts everyone's ante
+    pub straddle: bool,        // UTG posts a blind straddle of 2x the big blind
+    pub bomb_pot_ante: u64,    // Ante for bomb pot hands, 0 = bomb pots disabled
+}
+
+impl ForcedBets {
+    pub const LEN: usize = 8 + 1 + 1 + 8;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum PlayerAction {
     Fold,
     Check,
     Call,
-    Raise(u64),
+    Raise(u64), // Raise to this total bet for the street
     AllIn,
 }
 
@@ -52,14 +86,30 @@ pub struct Table {
     pub arcium_mxe_account: Pubkey,    // Arcium MXE reference
     pub arcium_computation_id: u64,     // Active computation
     pub encrypted_deck_hash: [u8; 32], // On-chain deck commitment
+    pub deck_next_index: u8,           // Next undealt card in the encrypted deck
+    pub deck_burn_count: u8,
+    pub deck_nonce: u128,              // MXE-encrypted deck, read back by the deal circuits
+    pub deck_ciphertexts: [[u8; 32]; 2],
     pub community_cards: [u8; 5],      // Revealed community cards (255 = not yet revealed)
     pub main_pot: u64,
     pub side_pots: [u64; MAX_PLAYERS],
     pub side_pot_count: u8,
+    pub main_pot_eligible: u8,               // Bit i set: player_id i can win the main pot
+    pub side_pot_eligible: [u8; MAX_PLAYERS], // Same, per side pot
+    pub departed_contributions: [u64; MAX_PLAYERS], // Chips put in this hand by players who have left
     pub last_action_ts: i64,
     // Betting round tracking
     pub players_acted: u8,
     pub players_to_act: u8,
+    pub min_raise: u64,      // Size of the last full raise; the next raise must match it
+    pub full_raise_bet: u64, // Bet set by the last full raise; short all-ins don't reopen action
+    pub betting_structure: BettingStructure,
+    pub raises_this_street: u8,
+    pub forced_bets: ForcedBets,
+    pub bomb_pot_next: bool,           // Set by schedule_bomb_pot for the next hand
+    pub is_bomb_pot: bool,             // Current hand: antes only, betting starts on the flop
+    pub timeout_reward: u64,           // Lamports paid from the table's balance for cranking a timeout
+    pub seats: [Pubkey; MAX_PLAYERS],  // Player PDA in each seat, default when empty
     // Token-gating
     pub token_gate_mint: Option<Pubkey>,
     pub token_gate_amount: u64,
@@ -85,16 +135,37 @@ impl Table {
         + 32   // arcium_mxe_account
         + 8    // arcium_computation_id
         + 32   // encrypted_deck_hash
+        + 1    // deck_next_index
+        + 1    // deck_burn_count
+        + 16   // deck_nonce
+        + 64   // deck_ciphertexts
         + 5    // community_cards
         + 8    // main_pot
         + 48   // side_pots (6 * 8)
         + 1    // side_pot_count
+        + 1    // main_pot_eligible
+        + 6    // side_pot_eligible
+        + 48   // departed_contributions (6 * 8)
         + 8    // last_action_ts
         + 1    // players_acted
         + 1    // players_to_act
+        + 8    // min_raise
+        + 8    // full_raise_bet
+        + 1    // betting_structure (enum)
+        + 1    // raises_this_street
+        + ForcedBets::LEN
+        + 1    // bomb_pot_next
+        + 1    // is_bomb_pot
+        + 8    // timeout_reward
+        + 192  // seats (6 * 32)
         + 33   // token_gate_mint (Option<Pubkey>)
         + 8    // token_gate_amount
         + 1;   // bump
+
+    /// Byte offset of `deck_ciphertexts`, passed to Arcium as an account argument
+    pub const DECK_CIPHERTEXTS_OFFSET: u32 = 8 + 8 + 32 + 32 + 8 + 8 + 1 + 1 + 1
+        + 1    // phase (borsh tag)
+        + 8 + 8 + 1 + 1 + 8 + 32 + 8 + 32 + 1 + 1 + 16;
 }
 
 #[account]
@@ -111,6 +182,7 @@ pub struct Player {
     pub has_acted: bool,
     pub time_bank_remaining: i64,
     pub encrypted_hand_hash: [u8; 32], // Commitment to hole cards
+    pub encryption_pubkey: [u8; 32],   // x25519 key hole cards are encrypted to
     pub joined_at: i64,
     pub last_reaction: u8,        // 0 = none, 1-5 = emoji types
     pub last_reaction_ts: i64,
@@ -118,11 +190,15 @@ pub struct Player {
     pub last_message_ts: i64,
     pub action_count: u8,         // Increments on every action in a hand
     pub last_hand: u64,           // Used to reset action_count on new hand
+    pub is_sitting_out: bool,     // Dealt out of new hands until sit_in
+    pub missed_blinds: u64,       // Big blind owed on return from sitting out
+    pub timed_out_hands: u8,      // Hands timed out since the player last acted
+    pub last_timeout_hand: u64,
     pub bump: u8,
 }
 
 impl Player {
-    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 8 + 8 + 8 + 1 + 1 + 1 + 8 + 32 + 8 + 1 + 8 + 64 + 8 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1 + 8 + 8 + 8 + 1 + 1 + 1 + 8 + 32 + 32 + 8 + 1 + 8 + 64 + 8 + 1 + 8 + 1 + 8 + 1 + 8 + 1;
 }
 
 #[account]
@@ -130,14 +206,18 @@ pub struct EncryptedHand {
     pub player: Pubkey,
     pub table: Pubkey,
     pub hand_number: u64,
-    pub encrypted_card1: [u8; 64],  // Encrypted under player's pubkey
-    pub encrypted_card2: [u8; 64],
+    pub encryption_key: [u8; 32],      // Player's x25519 key
+    pub nonce: u128,
+    pub encrypted_cards: [[u8; 32]; 2], // Hole cards + commitment salt, packed
     pub arcium_commitment: [u8; 32], // Arcium's commitment to the hand
     pub bump: u8,
 }
 
 impl EncryptedHand {
-    pub const LEN: usize = 8 + 32 + 32 + 8 + 64 + 64 + 32 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 32 + 16 + 64 + 32 + 1;
+
+    /// Byte offset of `encrypted_cards`, passed to Arcium as an account argument
+    pub const CIPHERTEXTS_OFFSET: u32 = 8 + 32 + 32 + 8 + 32 + 16;
 }
 
 #[account]
@@ -178,13 +258,155 @@ impl GameResult {
     pub const LEN: usize = 8 + 32 + 8 + 192 + 1 + 48 + 1 + 5 + 192 + 1 + 256 + 32 + 8 + 1;
 }
 
+/// A prove_hand statement about a player's hand, checked against the hand
+/// commitments when Arcium's output lands
+#[account]
+pub struct HandProof {
+    pub player: Pubkey,            // Player PDA
+    pub table: Pubkey,
+    pub hand_number: u64,
+    pub disclosure: u8,
+    pub computation_id: u64,
+    pub winner_commitment: [u8; 32], // Commitment of the hand the proof compares against
+    pub verified: bool,
+    pub cards: [u8; 2],            // 255 unless disclosed
+    pub hand_category: u8,         // 255 unless disclosed
+    pub weaker_than_winner: u8,    // 255 unless disclosed
+    pub proof_hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl HandProof {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 8 + 32 + 1 + 2 + 1 + 1 + 32 + 1;
+
+    /// The EncryptedHand PDA this proof is about
+    pub fn player_hand(&self, table: &Pubkey) -> Pubkey {
+        let seeds: &[&[u8]] = &[b"hand", table.as_ref(), &self.hand_number.to_le_bytes(), self.player.as_ref()];
+        Pubkey::find_program_address(seeds, &crate::ID).0
+    }
+}
+
+// ===== Arcium Computation Outputs =====
+// Borsh layouts of each circuit's outputs, matching what `#[arcium_callback]`
+// generates from the `arcium build` interface files.
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateEncryptedDeckOutput {
+    pub deck: MXEEncryptedStruct<2>,
+    pub next_index: u8,
+    pub burn_count: u8,
+}
+
+impl HasSize for CreateEncryptedDeckOutput {
+    const SIZE: usize = 16 + 64 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DealAllHoleCardsOutput {
+    pub hands: [SharedEncryptedStruct<2>; MAX_PLAYERS],
+    pub commitments: [[u8; 32]; MAX_PLAYERS],
+    pub deck_next_index: u8,
+}
+
+impl HasSize for DealAllHoleCardsOutput {
+    const SIZE: usize = (32 + 16 + 64) * MAX_PLAYERS + 32 * MAX_PLAYERS + 1;
+}
+
+/// Shared by `deal_flop`, `deal_turn` and `deal_river`
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DealStreetOutput {
+    pub community_cards: [u8; 5],
+    pub revealed_count: u8,
+    pub deck_next_index: u8,
+    pub deck_burn_count: u8,
+}
+
+impl HasSize for DealStreetOutput {
+    const SIZE: usize = 5 + 1 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteShowdownOutput {
+    pub winners: [u8; MAX_PLAYERS],
+    pub winner_count: u8,
+    pub payouts: [u64; MAX_PLAYERS],
+    pub winning_hand_category: u8,
+}
+
+impl HasSize for ExecuteShowdownOutput {
+    const SIZE: usize = MAX_PLAYERS + 1 + 8 * MAX_PLAYERS + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RunOutShowdownOutput {
+    pub community_cards: [u8; 5],
+    pub revealed_count: u8,
+    pub hole_cards: [[u8; 2]; MAX_PLAYERS], // 255 for seats not in the showdown
+    pub showdown: ExecuteShowdownOutput,
+}
+
+impl HasSize for RunOutShowdownOutput {
+    const SIZE: usize = 5 + 1 + 2 * MAX_PLAYERS + ExecuteShowdownOutput::SIZE;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProveHandOutput {
+    pub disclosure: u8,
+    pub commitment: [u8; 32],
+    pub winner_commitment: [u8; 32],
+    pub cards: [u8; 2],
+    pub salt: [u8; 29],
+    pub hand_category: u8,
+    pub weaker_than_winner: u8,
+}
+
+impl HasSize for ProveHandOutput {
+    const SIZE: usize = 1 + 32 + 32 + 2 + 29 + 1 + 1;
+}
+
 // ===== Program =====
 
-#[program]
+#[arcium_program]
 pub mod encrypted_poker {
     use super::*;
 
+    // Computation definitions: each circuit has to be registered with the
+    // MXE once after deploy, before anything can queue it
+
+    pub fn init_create_encrypted_deck_comp_def(ctx: Context<InitCreateEncryptedDeckCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_deal_all_hole_cards_comp_def(ctx: Context<InitDealAllHoleCardsCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_deal_flop_comp_def(ctx: Context<InitDealFlopCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_deal_turn_comp_def(ctx: Context<InitDealTurnCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_deal_river_comp_def(ctx: Context<InitDealRiverCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_execute_showdown_comp_def(ctx: Context<InitExecuteShowdownCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_run_out_showdown_comp_def(ctx: Context<InitRunOutShowdownCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
+    pub fn init_prove_hand_comp_def(ctx: Context<InitProveHandCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)
+    }
+
     /// Create a new poker table
+    #[allow(clippy::too_many_arguments)]
     pub fn create_table(
         ctx: Context<CreateTable>,
         table_id: u64,
@@ -193,11 +415,18 @@ pub mod encrypted_poker {
         big_blind: u64,
         min_players: u8,
         max_players: u8,
+        betting_structure: BettingStructure,
+        forced_bets: ForcedBets,
+        timeout_reward: u64,
         token_gate_mint: Option<Pubkey>,
         token_gate_amount: u64,
     ) -> Result<()> {
         require!(small_blind > 0, PokerError::InvalidBlind);
         require!(big_blind == small_blind * 2, PokerError::InvalidBlind);
+        require!(
+            !forced_bets.big_blind_ante || forced_bets.ante > 0,
+            PokerError::InvalidBlind
+        );
         require!(min_players >= 2 && min_players <= max_players, PokerError::InvalidPlayerCount);
         require!(max_players <= MAX_PLAYERS as u8, PokerError::InvalidPlayerCount);
 
@@ -205,10 +434,14 @@ pub mod encrypted_poker {
         table.table_id = table_id;
         table.name = name;
         table.creator = ctx.accounts.creator.key();
+        table.arcium_mxe_account = ctx.accounts.arcium_mxe.key();
         table.small_blind = small_blind;
         table.big_blind = big_blind;
         table.min_players = min_players;
         table.max_players = max_players;
+        table.betting_structure = betting_structure;
+        table.forced_bets = forced_bets;
+        table.timeout_reward = timeout_reward;
         table.current_players = 0;
         table.phase = GamePhase::Waiting;
         table.pot = 0;
@@ -230,15 +463,27 @@ pub mod encrypted_poker {
     }
 
     /// Join a poker table (with optional token-gate check)
-    pub fn join_table(ctx: Context<JoinTable>, seat_index: u8) -> Result<()> {
+    /// player_ids follow the seats clockwise, so joining shifts the players
+    /// seated after the new one up by one.
+    /// remaining_accounts: the Player PDAs seated after `seat_index` (writable), in player_id order.
+    pub fn join_table(
+        ctx: Context<JoinTable>,
+        seat_index: u8,
+        encryption_pubkey: [u8; 32],
+    ) -> Result<()> {
         let table = &mut ctx.accounts.table;
 
-        require!(table.phase == GamePhase::Waiting, PokerError::GameAlreadyStarted);
+        // Seats open before the first hand and between hands
         require!(
-            (table.current_players as usize) < MAX_PLAYERS,
-            PokerError::TableFull
+            matches!(table.phase, GamePhase::Waiting | GamePhase::Complete),
+            PokerError::GameAlreadyStarted
         );
+        require!(table.current_players < table.max_players, PokerError::TableFull);
         require!(seat_index < table.max_players, PokerError::InvalidSeat);
+        require!(
+            table.seats[seat_index as usize] == Pubkey::default(),
+            PokerError::SeatTaken
+        );
 
         // Token-gate check
         if let Some(mint) = table.token_gate_mint {
@@ -254,11 +499,36 @@ pub mod encrypted_poker {
             );
         }
 
+        let player_id = table.seats[..seat_index as usize]
+            .iter()
+            .filter(|&&occupant| occupant != Pubkey::default())
+            .count() as u8;
+        require!(
+            ctx.remaining_accounts.len() == (table.current_players - player_id) as usize,
+            PokerError::InvalidPlayerCount
+        );
+        for (i, acc) in ctx.remaining_accounts.iter().enumerate() {
+            require!(acc.owner == &crate::ID, PokerError::InvalidGame);
+            let mut other = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+            require!(
+                other.table == table.key()
+                    && other.player_id as usize == player_id as usize + i
+                    && table.seats[other.seat_index as usize] == acc.key(),
+                PokerError::InvalidGame
+            );
+            other.player_id += 1;
+            other.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+        }
+        if table.hand_number > 0 && table.current_players > 0 && table.dealer_seat >= player_id {
+            table.dealer_seat += 1;
+        }
+
         let player = &mut ctx.accounts.player;
-        player.player_id = table.current_players;
+        player.player_id = player_id;
         player.wallet = ctx.accounts.payer.key();
         player.table = table.key();
         player.seat_index = seat_index;
+        player.encryption_pubkey = encryption_pubkey;
         player.chip_count = STARTING_CHIPS;
         player.current_bet = 0;
         player.is_active = true;
@@ -268,8 +538,13 @@ pub mod encrypted_poker {
         player.joined_at = Clock::get()?.unix_timestamp;
         player.action_count = 0;
         player.last_hand = 0;
+        player.is_sitting_out = false;
+        player.missed_blinds = 0;
+        player.timed_out_hands = 0;
+        player.last_timeout_hand = 0;
         player.bump = ctx.bumps.player;
 
+        table.seats[seat_index as usize] = player.key();
         table.current_players += 1;
 
         emit!(PlayerJoined {
@@ -281,9 +556,104 @@ pub mod encrypted_poker {
         Ok(())
     }
 
-    /// Start the game - initiates Arcium deck shuffle
-    pub fn start_game(ctx: Context<StartGame>) -> Result<()> {
+    /// Leave the table, closing the Player account and returning its rent to the
+    /// wallet. Mid-hand a live player leaves on their turn by folding; chips
+    /// already in the pot stay there. Later seats move down one player_id.
+    /// remaining_accounts: every other seated Player PDA (writable) in player_id order.
+    pub fn leave_table(ctx: Context<LeaveTable>) -> Result<()> {
         let table = &mut ctx.accounts.table;
+        let player = &mut ctx.accounts.player;
+        let table_key = table.key();
+        let leaver_id = player.player_id;
+
+        // Busted players no longer hold a seat
+        if leaver_id != UNSEATED {
+            require!(table.phase != GamePhase::Showdown, PokerError::InvalidPhase);
+            require!(
+                ctx.remaining_accounts.len() + 1 == table.current_players as usize,
+                PokerError::InvalidPlayerCount
+            );
+            for (i, acc) in ctx.remaining_accounts.iter().enumerate() {
+                require!(acc.owner == &crate::ID, PokerError::InvalidGame);
+                let other = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+                let expected = if (i as u8) < leaver_id { i as u8 } else { i as u8 + 1 };
+                require!(
+                    other.table == table_key && other.player_id == expected,
+                    PokerError::InvalidGame
+                );
+            }
+
+            let in_hand = !matches!(table.phase, GamePhase::Waiting | GamePhase::Complete);
+            if in_hand && player.is_active {
+                require!(!player.is_all_in, PokerError::PlayerAllIn);
+                require!(leaver_id == table.current_turn, PokerError::NotYourTurn);
+                require!(is_deal_settled(table), PokerError::DealPending);
+
+                let now = Clock::get()?.unix_timestamp;
+                player.is_active = false;
+                if table.players_to_act > 0 {
+                    table.players_to_act -= 1;
+                }
+                table.last_action_ts = now;
+                advance_turn(table, table_key, ctx.remaining_accounts, Some(&**player))?;
+                award_to_last_player(
+                    table,
+                    player,
+                    ctx.remaining_accounts,
+                    ctx.accounts.game_result.as_deref(),
+                    &ctx.accounts.wallet,
+                    &ctx.accounts.system_program,
+                    now,
+                )?;
+            }
+
+            // Folded chips still count toward the pots they reached
+            if in_hand && table.phase != GamePhase::Complete && player.total_contributed > 0 {
+                if let Some(slot) = table.departed_contributions.iter_mut().find(|amount| **amount == 0) {
+                    *slot = player.total_contributed;
+                }
+            }
+
+            for acc in ctx.remaining_accounts {
+                let mut other = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+                if other.player_id > leaver_id {
+                    other.player_id -= 1;
+                    other.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+                }
+            }
+
+            let seated = table.current_players - 1;
+            table.current_players = seated;
+            if table.current_turn != 255 && table.current_turn > leaver_id {
+                table.current_turn -= 1;
+            }
+            // A departing button passes back a seat, so the next one along acts first
+            table.dealer_seat = if table.dealer_seat > leaver_id {
+                table.dealer_seat - 1
+            } else if table.dealer_seat == leaver_id && seated > 0 {
+                (leaver_id + seated - 1) % seated
+            } else {
+                table.dealer_seat
+            };
+        }
+
+        if table.seats[player.seat_index as usize] == player.key() {
+            table.seats[player.seat_index as usize] = Pubkey::default();
+        }
+
+        emit!(PlayerLeft {
+            table_id: table.table_id,
+            player: player.wallet,
+            chip_count: player.chip_count,
+        });
+
+        Ok(())
+    }
+
+    /// Start the game - queues the Arcium deck shuffle and posts the blinds.
+    /// remaining_accounts: every Player PDA (writable) in player_id order.
+    pub fn start_game(ctx: Context<StartGame>, computation_offset: u64) -> Result<()> {
+        let table = &ctx.accounts.table;
 
         require!(table.phase == GamePhase::Waiting, PokerError::GameAlreadyStarted);
         require!(
@@ -291,18 +661,150 @@ pub mod encrypted_poker {
             PokerError::NotEnoughPlayers
         );
 
-        table.hand_number += 1;
-        table.phase = GamePhase::PreFlop;
-        let now = Clock::get()?.unix_timestamp;
-        table.last_action_ts = now;
+        begin_hand(
+            ctx.accounts,
+            ctx.bumps.sign_pda_account,
+            computation_offset,
+            ctx.remaining_accounts,
+        )
+    }
+
+    /// Settle a completed hand's seats and deal the next one with a fresh shuffle.
+    /// Busted players are unseated and the rest renumbered; if too few remain,
+    /// the table goes back to Waiting for new players instead.
+    /// remaining_accounts: every Player PDA (writable) in player_id order.
+    pub fn start_next_hand(ctx: Context<StartGame>, computation_offset: u64) -> Result<()> {
+        let table = &mut ctx.accounts.table;
+        require!(table.phase == GamePhase::Complete, PokerError::InvalidPhase);
+        let player_count = table.current_players;
+        require!(
+            ctx.remaining_accounts.len() == player_count as usize,
+            PokerError::InvalidPlayerCount
+        );
+
+        let table_key = table.key();
+        let old_dealer = table.dealer_seat;
+        let mut seated: u8 = 0;
+        let mut dealt_in: u8 = 0;
+        let mut dealer_seat = None;
+        let mut seated_before_dealer: u8 = 0;
+        let mut seated_accounts = Vec::with_capacity(player_count as usize);
+
+        for (i, acc) in ctx.remaining_accounts.iter().enumerate() {
+            require!(acc.owner == &crate::ID, PokerError::InvalidGame);
+            let mut player = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+            require!(
+                player.table == table_key && player.player_id as usize == i,
+                PokerError::InvalidGame
+            );
+
+            player.current_bet = 0;
+            player.total_contributed = 0;
+            player.is_all_in = false;
+            player.has_acted = false;
+            player.encrypted_hand_hash = [0u8; 32];
+            // Action PDAs are seeded by action_count, so it restarts before the first action
+            player.action_count = 0;
+            player.last_hand = table.hand_number + 1;
+
+            if player.chip_count == 0 {
+                player.player_id = UNSEATED;
+                player.is_active = false;
+                table.seats[player.seat_index as usize] = Pubkey::default();
+                emit!(PlayerBusted {
+                    table_id: table.table_id,
+                    player: player.wallet,
+                });
+            } else {
+                if i < old_dealer as usize {
+                    seated_before_dealer += 1;
+                } else if i == old_dealer as usize {
+                    dealer_seat = Some(seated);
+                }
+                player.player_id = seated;
+                player.is_active = !player.is_sitting_out;
+                seated += 1;
+                if player.is_active {
+                    dealt_in += 1;
+                }
+                seated_accounts.push(acc.clone());
+            }
+            player.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+        }
+
+        // Park the button on the last surviving seat at or before the old
+        // button, so the rotation in begin_hand lands on the next one along
+        let table = &mut ctx.accounts.table;
+        table.current_players = seated;
+        table.dealer_seat = match dealer_seat {
+            Some(seat) => seat,
+            None if seated > 0 => (seated_before_dealer + seated - 1) % seated,
+            None => 0,
+        };
+
+        if dealt_in < table.min_players {
+            table.phase = GamePhase::Waiting;
+            return Ok(());
+        }
+
+        begin_hand(
+            ctx.accounts,
+            ctx.bumps.sign_pda_account,
+            computation_offset,
+            &seated_accounts,
+        )
+    }
 
-        // Initialize betting state for PreFlop
-        reset_betting_state(table, ctx.remaining_accounts, now);
+    /// Make the next hand a bomb pot: everyone antes and the hand starts at the flop
+    pub fn schedule_bomb_pot(ctx: Context<ScheduleBombPot>) -> Result<()> {
+        let table = &mut ctx.accounts.table;
+        require!(table.forced_bets.bomb_pot_ante > 0, PokerError::BombPotsDisabled);
+        table.bomb_pot_next = true;
 
-        emit!(GameStarted {
+        emit!(BombPotScheduled {
             table_id: table.table_id,
-            hand_number: table.hand_number,
-            player_count: table.current_players,
+            hand_number: table.hand_number + 1,
+        });
+
+        Ok(())
+    }
+
+    /// Sit out: dealt out of hands until sit_in. Allowed between hands or
+    /// once the player has folded.
+    pub fn sit_out(ctx: Context<SitOut>) -> Result<()> {
+        let table = &ctx.accounts.table;
+        let player = &mut ctx.accounts.player;
+        let between_hands = matches!(table.phase, GamePhase::Waiting | GamePhase::Complete);
+        require!(between_hands || !player.is_active, PokerError::PlayerInHand);
+
+        player.is_sitting_out = true;
+        player.is_active = false;
+
+        emit!(PlayerSatOut {
+            table_id: table.table_id,
+            player: player.wallet,
+            auto: false,
+        });
+
+        Ok(())
+    }
+
+    /// Come back from sitting out; the player is dealt in from the next hand
+    /// and posts any big blind they missed
+    pub fn sit_in(ctx: Context<SitOut>) -> Result<()> {
+        let table = &ctx.accounts.table;
+        let player = &mut ctx.accounts.player;
+        require!(player.is_sitting_out, PokerError::NotSittingOut);
+
+        player.is_sitting_out = false;
+        player.timed_out_hands = 0;
+        if table.phase == GamePhase::Waiting {
+            player.is_active = true;
+        }
+
+        emit!(PlayerSatIn {
+            table_id: table.table_id,
+            player: player.wallet,
         });
 
         Ok(())
@@ -310,19 +812,29 @@ pub mod encrypted_poker {
 
     /// Callback from Arcium: encrypted deck is ready
     pub fn on_deck_ready(
-        ctx: Context<ArciumCallback>,
-        computation_id: u64,
-        encrypted_deck_hash: [u8; 32],
+        ctx: Context<DeckReadyCallback>,
+        output: SignedComputationOutputs<CreateEncryptedDeckOutput>,
     ) -> Result<()> {
         // Verify this callback is from Arcium
-        verify_arcium_callback(&ctx.accounts.arcium_mxe)?;
+        verify_arcium_callback(
+            &ctx.accounts.table,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+        let deck = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
 
         let table = &mut ctx.accounts.table;
-        table.encrypted_deck_hash = encrypted_deck_hash;
-        table.arcium_computation_id = computation_id;
-
-        // Post blind bets
-        post_blinds(table)?;
+        table.deck_nonce = deck.deck.nonce;
+        table.deck_ciphertexts = deck.deck.ciphertexts;
+        table.deck_next_index = deck.next_index;
+        table.deck_burn_count = deck.burn_count;
+        table.encrypted_deck_hash = hashv(&[
+            &deck.deck.nonce.to_le_bytes(),
+            &deck.deck.ciphertexts[0],
+            &deck.deck.ciphertexts[1],
+        ])
+        .to_bytes();
 
         emit!(DeckReady {
             table_id: table.table_id,
@@ -332,46 +844,168 @@ pub mod encrypted_poker {
         Ok(())
     }
 
-    /// Callback from Arcium: hole cards dealt to player
-    pub fn on_cards_dealt(
-        ctx: Context<ArciumCallback>,
-        player_id: u8,
-        encrypted_card1: [u8; 64],
-        encrypted_card2: [u8; 64],
-        arcium_commitment: [u8; 32],
+    /// Deal hole cards to every player in the hand in one Arcium computation.
+    /// Sat-out players are dealt out; their EncryptedHand stays empty.
+    /// remaining_accounts: (Player PDA, EncryptedHand PDA) pairs in player_id order.
+    pub fn deal_hole_cards<'info>(
+        ctx: Context<'_, '_, '_, 'info, DealHoleCards<'info>>,
+        computation_offset: u64,
     ) -> Result<()> {
-        verify_arcium_callback(&ctx.accounts.arcium_mxe)?;
-
-        let hand = &mut ctx.accounts.encrypted_hand;
-        hand.player = ctx.accounts.player.key();
-        hand.table = ctx.accounts.table.key();
-        hand.hand_number = ctx.accounts.table.hand_number;
-        hand.encrypted_card1 = encrypted_card1;
-        hand.encrypted_card2 = encrypted_card2;
-        hand.arcium_commitment = arcium_commitment;
-        hand.bump = ctx.bumps.encrypted_hand;
-
-        emit!(CardsDealt {
-            table_id: ctx.accounts.table.table_id,
-            player_id,
-            hand_number: ctx.accounts.table.hand_number,
-        });
+        let table = &ctx.accounts.table;
+        require!(table.phase == GamePhase::PreFlop, PokerError::InvalidPhase);
+        require!(
+            table.encrypted_deck_hash != [0u8; 32] && table.deck_next_index == 0,
+            PokerError::DeckNotReady
+        );
+        let player_count = table.current_players as usize;
+        require!(
+            ctx.remaining_accounts.len() == player_count * 2,
+            PokerError::InvalidPlayerCount
+        );
+
+        let table_key = table.key();
+        let hand_number = table.hand_number.to_le_bytes();
+        // Dealt-in players fill the circuit's slots in player_id order
+        let mut keys = [[0u8; 32]; MAX_PLAYERS];
+        let mut nonces = [0u128; MAX_PLAYERS];
+        let mut dealt: usize = 0;
+        let mut callback_accounts = vec![CallbackAccount { pubkey: table_key, is_writable: true }];
+
+        for (i, accounts) in ctx.remaining_accounts.chunks(2).enumerate() {
+            let (player_info, hand_info) = (&accounts[0], &accounts[1]);
+            require!(player_info.owner == &crate::ID, PokerError::InvalidGame);
+            let player = Player::try_deserialize(&mut &player_info.data.borrow()[..])?;
+            require!(player.table == table_key, PokerError::InvalidGame);
+            require!(player.player_id as usize == i, PokerError::InvalidPlayerCount);
+
+            let seeds: &[&[u8]] = &[b"hand", table_key.as_ref(), &hand_number, player_info.key.as_ref()];
+            let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+            require_keys_eq!(hand_info.key(), expected, PokerError::InvalidGame);
+
+            init_pda_account(
+                &ctx.accounts.creator,
+                hand_info,
+                &ctx.accounts.system_program,
+                EncryptedHand::LEN,
+                &[b"hand", table_key.as_ref(), &hand_number, player_info.key.as_ref(), &[bump]],
+            )?;
+
+            // Filled in by on_all_cards_dealt
+            let hand = EncryptedHand {
+                player: player_info.key(),
+                table: table_key,
+                hand_number: table.hand_number,
+                encryption_key: player.encryption_pubkey,
+                nonce: 0,
+                encrypted_cards: [[0u8; 32]; 2],
+                arcium_commitment: [0u8; 32],
+                bump,
+            };
+            hand.try_serialize(&mut &mut hand_info.try_borrow_mut_data()?[..])?;
+
+            if player.is_active && !player.is_sitting_out {
+                keys[dealt] = player.encryption_pubkey;
+                nonces[dealt] = hand_nonce(&table_key, table.hand_number, player.player_id);
+                dealt += 1;
+                callback_accounts.push(CallbackAccount { pubkey: hand_info.key(), is_writable: true });
+            }
+        }
+        require!(dealt >= 2, PokerError::NotEnoughPlayers);
+
+        // Unused slots are still encrypted to (and discarded by) the first
+        // dealt-in player, under nonces no seat uses
+        let mut args = ArgBuilder::new()
+            .plaintext_u128(table.deck_nonce)
+            .account(table_key, Table::DECK_CIPHERTEXTS_OFFSET, 64)
+            .plaintext_u8(table.deck_next_index);
+        for slot in 0..MAX_PLAYERS {
+            let (key, nonce) = if slot < dealt {
+                (keys[slot], nonces[slot])
+            } else {
+                let unused = (MAX_PLAYERS + slot) as u8;
+                (keys[0], hand_nonce(&table_key, table.hand_number, unused))
+            };
+            args = args.x25519_pubkey(key).plaintext_u128(nonce);
+        }
+        let args = args.plaintext_u8(dealt as u8).build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let callback = callback_ix(
+            instruction::OnAllCardsDealt::DISCRIMINATOR,
+            comp_def_offset("deal_all_hole_cards"),
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?;
+        queue_computation(ctx.accounts, computation_offset, args, vec![callback], 1, 0)?;
+
+        ctx.accounts.table.arcium_computation_id = computation_offset;
 
         Ok(())
     }
 
-    /// Player submits a betting action
-    pub fn submit_action(
-        ctx: Context<SubmitAction>,
-        action_type: u8,
-        raise_amount: u64,
+    /// Callback from Arcium: hole cards dealt to every player in the hand in one computation.
+    /// remaining_accounts: the dealt-in players' EncryptedHand PDAs in player_id order.
+    pub fn on_all_cards_dealt(
+        ctx: Context<CardsDealtCallback>,
+        output: SignedComputationOutputs<DealAllHoleCardsOutput>,
     ) -> Result<()> {
+        verify_arcium_callback(
+            &ctx.accounts.table,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+        let dealt = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
+        require!(
+            ctx.remaining_accounts.len() <= MAX_PLAYERS,
+            PokerError::InvalidPlayerCount
+        );
+
+        let table = &mut ctx.accounts.table;
+        let table_key = table.key();
+
+        for (slot, hand_info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(hand_info.owner == &crate::ID, PokerError::InvalidGame);
+            let mut hand = EncryptedHand::try_deserialize(&mut &hand_info.data.borrow()[..])?;
+            require!(
+                hand.table == table_key && hand.hand_number == table.hand_number,
+                PokerError::InvalidGame
+            );
+
+            let encrypted = &dealt.hands[slot];
+            hand.encryption_key = encrypted.encryption_key;
+            hand.nonce = encrypted.nonce;
+            hand.encrypted_cards = encrypted.ciphertexts;
+            hand.arcium_commitment = dealt.commitments[slot];
+            hand.try_serialize(&mut &mut hand_info.try_borrow_mut_data()?[..])?;
+
+            emit!(CardsDealt {
+                table_id: table.table_id,
+                player: hand.player,
+                hand_number: table.hand_number,
+            });
+        }
+
+        table.deck_next_index = dealt.deck_next_index;
+        // The clock starts once the first player can see their cards
+        table.last_action_ts = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    /// Player submits a betting action.
+    /// remaining_accounts: every other seated Player PDA; the last player left
+    /// after a fold must be writable so the pot can be paid to them.
+    pub fn submit_action(ctx: Context<SubmitAction>, action: PlayerAction) -> Result<()> {
         let table = &mut ctx.accounts.table;
         let player = &mut ctx.accounts.player;
 
+        require!(is_betting_phase(&table.phase), PokerError::InvalidPhase);
         require!(player.is_active, PokerError::PlayerInactive);
         require!(player.wallet == ctx.accounts.payer.key(), PokerError::NotYourTurn);
         require!(table.current_turn == player.player_id, PokerError::NotYourTurn);
+        require!(is_deal_settled(table), PokerError::DealPending);
 
         // Time bank check
         let now = Clock::get()?.unix_timestamp;
@@ -386,23 +1020,36 @@ pub mod encrypted_poker {
             player.time_bank_remaining -= elapsed - TIME_BANK_SECONDS;
         }
 
-        // Apply action
-        match action_type {
-            0 => { // Fold
+        // Apply action; `moved` is what goes from the player's stack into the pot
+        let call_amount = table.current_bet.saturating_sub(player.current_bet);
+        // Raising to exactly the whole stack is an all-in, as long as it
+        // actually raises; a stack that can't top the bet is still too small
+        let action = match action {
+            PlayerAction::Raise(raise_to)
+                if raise_to == player.current_bet + player.chip_count && raise_to > table.current_bet =>
+            {
+                PlayerAction::AllIn
+            }
+            action => action,
+        };
+        let moved = match action {
+            PlayerAction::Fold => {
                 player.is_active = false;
                 // One fewer player needs to act in this round
                 if table.players_to_act > 0 {
                     table.players_to_act -= 1;
                 }
                 player.has_acted = true;
+                0
             }
-            1 => { // Check
-                require!(table.current_bet == player.current_bet, PokerError::MustCallOrFold);
+            PlayerAction::Check => {
+                require!(call_amount == 0, PokerError::MustCallOrFold);
                 player.has_acted = true;
                 table.players_acted = table.players_acted.saturating_add(1);
+                0
             }
-            2 => { // Call
-                let call_amount = table.current_bet.saturating_sub(player.current_bet);
+            PlayerAction::Call => {
+                require!(call_amount > 0, PokerError::NothingToCall);
                 let actual_call = call_amount.min(player.chip_count);
                 player.chip_count -= actual_call;
                 player.current_bet += actual_call;
@@ -416,41 +1063,59 @@ pub mod encrypted_poker {
                 } else {
                     table.players_acted = table.players_acted.saturating_add(1);
                 }
+                actual_call
             }
-            3 => { // Raise — everyone else must act again
-                let call_amount = table.current_bet.saturating_sub(player.current_bet);
-                let total = call_amount + raise_amount;
+            PlayerAction::Raise(raise_to) => { // Everyone else must act again
+                require!(raise_to > table.current_bet, PokerError::RaiseTooSmall);
+                let total = raise_to - player.current_bet;
                 require!(player.chip_count >= total, PokerError::InsufficientChips);
-                require!(raise_amount >= table.big_blind, PokerError::RaiseTooSmall);
+                let raise_amount = raise_to - table.current_bet;
+                require!(raise_amount >= table.min_raise, PokerError::RaiseTooSmall);
+                require!(raise_amount <= max_raise(table, call_amount), PokerError::RaiseTooLarge);
+                require!(can_raise(table, player), PokerError::BettingNotReopened);
                 player.chip_count -= total;
-                player.current_bet = table.current_bet + raise_amount;
+                player.current_bet = raise_to;
                 player.total_contributed += total;
                 table.pot += total;
-                table.current_bet = player.current_bet;
+                table.current_bet = raise_to;
+                table.min_raise = raise_amount;
+                table.full_raise_bet = raise_to;
+                table.raises_this_street += 1;
                 player.has_acted = true;
                 // After a raise, all other active non-all-in players must act again
                 // Reset to 1 (this player already acted)
                 table.players_acted = 1;
+                total
             }
-            4 => { // All-in
+            PlayerAction::AllIn => {
                 let all_in = player.chip_count;
+                let raise_by = (player.current_bet + all_in).saturating_sub(table.current_bet);
+                if raise_by > 0 {
+                    require!(raise_by <= max_raise(table, call_amount), PokerError::RaiseTooLarge);
+                    require!(can_raise(table, player), PokerError::BettingNotReopened);
+                }
                 player.chip_count = 0;
                 player.current_bet += all_in;
                 player.total_contributed += all_in;
                 table.pot += all_in;
                 player.is_all_in = true;
                 player.has_acted = true;
-                if player.current_bet > table.current_bet {
-                    // Effective raise — others must act again
+                // An all-in player never acts again this hand
+                if table.players_to_act > 0 { table.players_to_act -= 1; }
+                if raise_by > 0 {
+                    // Everyone else has to at least call the extra. Only a full
+                    // raise lets players who already acted raise again.
                     table.current_bet = player.current_bet;
-                    table.players_acted = 1;
-                } else {
-                    // Pure call all-in — one fewer active player left
-                    if table.players_to_act > 0 { table.players_to_act -= 1; }
+                    table.players_acted = 0;
+                    if raise_by >= table.min_raise {
+                        table.min_raise = raise_by;
+                        table.full_raise_bet = player.current_bet;
+                        table.raises_this_street += 1;
+                    }
                 }
+                all_in
             }
-            _ => return Err(PokerError::InvalidAction.into()),
-        }
+        };
 
         // Auto-reset action count on new hand
         if player.last_hand != table.hand_number {
@@ -460,48 +1125,181 @@ pub mod encrypted_poker {
 
         // Increment action count for this player (ensures unique PDA for next action/street)
         player.action_count = player.action_count.saturating_add(1);
+        player.timed_out_hands = 0;
 
         table.last_action_ts = now;
-        advance_turn(table, ctx.remaining_accounts);
+        let table_key = table.key();
+        advance_turn(table, table_key, ctx.remaining_accounts, Some(&**player))?;
+
+        if action == PlayerAction::Fold {
+            award_to_last_player(
+                table,
+                player,
+                ctx.remaining_accounts,
+                ctx.accounts.game_result.as_deref(),
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                now,
+            )?;
+        }
 
         // Record encrypted action on-chain
-        let action = &mut ctx.accounts.encrypted_action;
-        action.player = player.wallet;
-        action.table = table.key();
-        action.hand_number = table.hand_number;
-        action.action_type = action_type;
-        action.timestamp = now;
-        action.bump = ctx.bumps.encrypted_action;
+        let action_type = action_to_u8(&action);
+        let record = &mut ctx.accounts.encrypted_action;
+        record.player = player.wallet;
+        record.table = table.key();
+        record.hand_number = table.hand_number;
+        record.action_type = action_type;
+        record.timestamp = now;
+        record.bump = ctx.bumps.encrypted_action;
 
         emit!(ActionSubmitted {
             table_id: table.table_id,
             player_id: player.player_id,
             action_type,
+            amount: moved,
             next_player: table.current_turn,
         });
 
         Ok(())
     }
 
-    /// Advance to next street (request Arcium to deal community cards)
-    pub fn deal_community_cards(ctx: Context<DealCards>) -> Result<()> {
+    /// Act for a player whose time bank has run out: check if that is free,
+    /// fold otherwise. A player who times out in `AFK_TIMED_OUT_HANDS` hands
+    /// without acting in between is folded and sat out. Anyone can call this; if the table account holds
+    /// enough lamports above rent, the caller earns `timeout_reward`.
+    /// remaining_accounts: as for submit_action.
+    pub fn timeout_player(ctx: Context<TimeoutPlayer>) -> Result<()> {
         let table = &mut ctx.accounts.table;
+        let player = &mut ctx.accounts.player;
+        require!(is_betting_phase(&table.phase), PokerError::InvalidPhase);
+        require!(table.current_turn != 255, PokerError::NotYourTurn);
+        require!(player.is_active && !player.is_all_in, PokerError::PlayerInactive);
+        require!(is_deal_settled(table), PokerError::DealPending);
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now - table.last_action_ts > TIME_BANK_SECONDS + player.time_bank_remaining,
+            PokerError::TimeNotExpired
+        );
+        player.time_bank_remaining = 0;
+        player.has_acted = true;
+
+        // Away for too many hands: fold and sit out
+        if player.last_timeout_hand != table.hand_number {
+            player.last_timeout_hand = table.hand_number;
+            player.timed_out_hands = player.timed_out_hands.saturating_add(1);
+        }
+        let away = player.timed_out_hands >= AFK_TIMED_OUT_HANDS;
+        if away {
+            player.is_sitting_out = true;
+            emit!(PlayerSatOut {
+                table_id: table.table_id,
+                player: player.wallet,
+                auto: true,
+            });
+        }
+
+        let action = if !away && table.current_bet == player.current_bet {
+            table.players_acted = table.players_acted.saturating_add(1);
+            PlayerAction::Check
+        } else {
+            player.is_active = false;
+            if table.players_to_act > 0 {
+                table.players_to_act -= 1;
+            }
+            PlayerAction::Fold
+        };
+
+        table.last_action_ts = now;
+        let table_key = table.key();
+        advance_turn(table, table_key, ctx.remaining_accounts, Some(&**player))?;
+
+        if action == PlayerAction::Fold {
+            award_to_last_player(
+                table,
+                player,
+                ctx.remaining_accounts,
+                ctx.accounts.game_result.as_deref(),
+                &ctx.accounts.caller,
+                &ctx.accounts.system_program,
+                now,
+            )?;
+        }
+
+        let table_info = table.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(table_info.data_len());
+        let reward = if table.timeout_reward > 0
+            && table_info.lamports() >= rent_floor + table.timeout_reward
+        {
+            table.timeout_reward
+        } else {
+            0
+        };
+        if reward > 0 {
+            **table_info.try_borrow_mut_lamports()? -= reward;
+            **ctx.accounts.caller.to_account_info().try_borrow_mut_lamports()? += reward;
+        }
+
+        emit!(PlayerTimedOut {
+            table_id: table.table_id,
+            player_id: player.player_id,
+            action_type: action_to_u8(&action),
+            next_player: table.current_turn,
+            reward,
+        });
+
+        Ok(())
+    }
 
-        require!(is_betting_complete(table, ctx.remaining_accounts), PokerError::BettingNotComplete);
+    /// Advance to next street (queues the Arcium deal for its community cards)
+    /// remaining_accounts: every seated Player PDA (writable).
+    pub fn deal_community_cards(ctx: Context<DealCards>, computation_offset: u64) -> Result<()> {
+        let table = &ctx.accounts.table;
+
+        require!(is_deal_settled(table), PokerError::DealPending);
+        require!(
+            is_betting_complete(table, table.key(), ctx.remaining_accounts)?,
+            PokerError::BettingNotComplete
+        );
 
+        // River -> Showdown goes through trigger_showdown
         let new_phase = match table.phase {
             GamePhase::PreFlop => GamePhase::Flop,
             GamePhase::Flop => GamePhase::Turn,
             GamePhase::Turn => GamePhase::River,
-            GamePhase::River => GamePhase::Showdown,
             _ => return Err(PokerError::InvalidPhase.into()),
         };
 
+        let table_key = table.key();
+        let revealed_count = table.community_cards.iter().filter(|&&c| c != 255).count() as u8;
+        let mut args = ArgBuilder::new()
+            .plaintext_u128(table.deck_nonce)
+            .account(table_key, Table::DECK_CIPHERTEXTS_OFFSET, 64)
+            .plaintext_u8(table.deck_next_index)
+            .plaintext_u8(table.deck_burn_count);
+        for card in table.community_cards {
+            args = args.plaintext_u8(card);
+        }
+        let args = args.plaintext_u8(revealed_count).build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let callback = callback_ix(
+            instruction::OnCommunityCards::DISCRIMINATOR,
+            ctx.accounts.comp_def_offset(),
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[CallbackAccount { pubkey: table_key, is_writable: true }],
+        )?;
+        queue_computation(ctx.accounts, computation_offset, args, vec![callback], 1, 0)?;
+
+        let table = &mut ctx.accounts.table;
         table.phase = new_phase.clone();
+        table.arcium_computation_id = computation_offset;
 
         // Reset betting for new street
         let now = Clock::get()?.unix_timestamp;
-        reset_betting_state(table, ctx.remaining_accounts, now);
+        reset_betting_state(table, table_key, ctx.remaining_accounts, now)?;
 
         emit!(StreetAdvanced {
             table_id: table.table_id,
@@ -513,17 +1311,23 @@ pub mod encrypted_poker {
 
     /// Callback from Arcium: community cards revealed
     pub fn on_community_cards(
-        ctx: Context<ArciumCallback>,
-        card_indices: Vec<u8>, // [0-4] indicating which cards to set
-        card_values: Vec<u8>,
+        ctx: Context<CommunityCardsCallback>,
+        output: SignedComputationOutputs<DealStreetOutput>,
     ) -> Result<()> {
-        verify_arcium_callback(&ctx.accounts.arcium_mxe)?;
+        verify_arcium_callback(
+            &ctx.accounts.table,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+        let street = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
 
         let table = &mut ctx.accounts.table;
-        for (idx, val) in card_indices.iter().zip(card_values.iter()) {
-            require!(*idx < 5, PokerError::InvalidCard);
-            table.community_cards[*idx as usize] = *val;
-        }
+        table.community_cards = street.community_cards;
+        table.deck_next_index = street.deck_next_index;
+        table.deck_burn_count = street.deck_burn_count;
+        // Nobody could act while the street was being dealt
+        table.last_action_ts = Clock::get()?.unix_timestamp;
 
         emit!(CommunityCardsDealt {
             table_id: table.table_id,
@@ -533,13 +1337,46 @@ pub mod encrypted_poker {
         Ok(())
     }
 
-    /// Trigger showdown computation in Arcium
-    pub fn trigger_showdown(ctx: Context<TriggerShowdown>) -> Result<()> {
+    /// Trigger showdown computation in Arcium.
+    /// remaining_accounts: (Player PDA, EncryptedHand PDA) pairs in player_id order.
+    pub fn trigger_showdown(ctx: Context<TriggerShowdown>, computation_offset: u64) -> Result<()> {
+        let table_key = ctx.accounts.table.key();
         let table = &mut ctx.accounts.table;
         require!(table.phase == GamePhase::River, PokerError::InvalidPhase);
-        require!(is_betting_complete(table, ctx.remaining_accounts), PokerError::BettingNotComplete);
+        require!(is_deal_settled(table), PokerError::DealPending);
+        let players: Vec<AccountInfo> = ctx.remaining_accounts.iter().step_by(2).cloned().collect();
+        require!(
+            is_betting_complete(table, table_key, &players)?,
+            PokerError::BettingNotComplete
+        );
+        require!(!is_all_in_hand(table, table_key, &players)?, PokerError::AllInRunOut);
+
+        let (mut args, callback_accounts) = showdown_hand_args(
+            table,
+            table_key,
+            ctx.accounts.game_result.key(),
+            ctx.remaining_accounts,
+        )?;
+        for card in table.community_cards {
+            args = args.plaintext_u8(card);
+        }
+        let args = args.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.game_result.bump = ctx.bumps.game_result;
+        let callback = callback_ix(
+            instruction::OnShowdownResult::DISCRIMINATOR,
+            comp_def_offset("execute_showdown"),
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?;
+        queue_computation(ctx.accounts, computation_offset, args, vec![callback], 1, 0)?;
 
+        let table = &mut ctx.accounts.table;
         table.phase = GamePhase::Showdown;
+        table.arcium_computation_id = computation_offset;
+        table.current_turn = 255;
 
         emit!(ShowdownTriggered {
             table_id: table.table_id,
@@ -549,100 +1386,164 @@ pub mod encrypted_poker {
         Ok(())
     }
 
-    /// Callback from Arcium: showdown result with ZK proof
+    /// Callback from Arcium: showdown result, signed by the cluster
     pub fn on_showdown_result(
-        ctx: Context<ShowdownResult>,
-        winners: [u8; MAX_PLAYERS],
-        winner_count: u8,
-        payouts: [u64; MAX_PLAYERS],
-        winning_hand_category: u8,
-        final_community_cards: Option<[u8; 5]>,
-        arcium_proof: [u8; 256],
-        proof_hash: [u8; 32],
+        ctx: Context<ShowdownResultCallback>,
+        output: SignedComputationOutputs<ExecuteShowdownOutput>,
     ) -> Result<()> {
-        verify_arcium_callback(&ctx.accounts.arcium_mxe)?;
+        verify_arcium_callback(
+            &ctx.accounts.table,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+
+        let (signature, proof_hash) = output_proof(&output);
+        let showdown = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
+
+        settle_showdown(
+            &mut ctx.accounts.table,
+            &mut ctx.accounts.game_result,
+            ctx.remaining_accounts,
+            &showdown,
+            signature,
+            proof_hash,
+        )
+    }
 
+    /// Deal the rest of the board and go to showdown in one computation, once
+    /// every live player but at most one is all-in and betting is over. An
+    /// all-in that closes on the river comes here too, to table the cards.
+    /// remaining_accounts: (Player PDA, EncryptedHand PDA) pairs in player_id order.
+    pub fn run_out_all_in(ctx: Context<RunOutAllIn>, computation_offset: u64) -> Result<()> {
+        let table_key = ctx.accounts.table.key();
         let table = &mut ctx.accounts.table;
-
-        // Store result on-chain
-        let result = &mut ctx.accounts.game_result;
-        result.table = table.key();
-        result.hand_number = table.hand_number;
-        result.winner_count = winner_count;
-        result.winning_hand_category = winning_hand_category;
-        
-        // Use provided final cards, or fall back to whatever is on the table
-        if let Some(cards) = final_community_cards {
-            result.community_cards = cards;
-            // Also update the table for consistency
-            table.community_cards = cards;
-        } else {
-            result.community_cards = table.community_cards;
-        }
-
-        result.arcium_proof = arcium_proof;
-        result.proof_hash = proof_hash;
-        result.timestamp = Clock::get()?.unix_timestamp;
-        result.bump = ctx.bumps.game_result;
-
-        // Resolve winner pubkeys from player_id index via remaining_accounts.
-        // Callers must pass each seated Player PDA as remaining_accounts
-        // in player_id order (0, 1, 2...).
-        let mut participant_count: u8 = 0;
-        for acc_info in ctx.remaining_accounts.iter() {
-            if let Ok(player) = Player::try_deserialize(&mut &acc_info.data.borrow()[..]) {
-                if participant_count < MAX_PLAYERS as u8 {
-                    result.participants[participant_count as usize] = player.wallet;
-                    participant_count += 1;
-                }
-            }
-        }
-        result.participant_count = participant_count;
-
-        for i in 0..(winner_count as usize) {
-            let winner_player_id = winners[i];
-            result.payouts[i] = payouts[i];
-
-            // Search remaining_accounts for the Player whose player_id matches
-            let mut resolved = Pubkey::default();
-            for acc_info in ctx.remaining_accounts.iter() {
-                if let Ok(player) = Player::try_deserialize(&mut &acc_info.data.borrow()[..]) {
-                    if player.player_id == winner_player_id {
-                        resolved = player.wallet;
-                        break;
-                    }
-                }
-            }
-            result.winners[i] = resolved;
+        require!(
+            matches!(
+                table.phase,
+                GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River
+            ),
+            PokerError::InvalidPhase
+        );
+        require!(is_deal_settled(table), PokerError::DealPending);
+        let players: Vec<AccountInfo> = ctx.remaining_accounts.iter().step_by(2).cloned().collect();
+        require!(
+            is_betting_complete(table, table_key, &players)?,
+            PokerError::BettingNotComplete
+        );
+        require!(is_all_in_hand(table, table_key, &players)?, PokerError::NotAllIn);
+
+        let (mut args, callback_accounts) = showdown_hand_args(
+            table,
+            table_key,
+            ctx.accounts.game_result.key(),
+            ctx.remaining_accounts,
+        )?;
+        for card in table.community_cards {
+            args = args.plaintext_u8(card);
         }
+        let revealed_count = table.community_cards.iter().filter(|&&c| c != 255).count() as u8;
+        let args = args
+            .plaintext_u8(revealed_count)
+            .plaintext_u128(table.deck_nonce)
+            .account(table_key, Table::DECK_CIPHERTEXTS_OFFSET, 64)
+            .plaintext_u8(table.deck_next_index)
+            .plaintext_u8(table.deck_burn_count)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.game_result.bump = ctx.bumps.game_result;
+        let callback = callback_ix(
+            instruction::OnRunOutResult::DISCRIMINATOR,
+            comp_def_offset("run_out_showdown"),
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &callback_accounts,
+        )?;
+        queue_computation(ctx.accounts, computation_offset, args, vec![callback], 1, 0)?;
 
-        table.phase = GamePhase::Complete;
+        let table = &mut ctx.accounts.table;
+        table.phase = GamePhase::Showdown;
+        table.arcium_computation_id = computation_offset;
+        table.current_turn = 255;
 
-        emit!(GameComplete {
+        emit!(ShowdownTriggered {
             table_id: table.table_id,
             hand_number: table.hand_number,
-            winner_count,
-            proof_hash,
         });
 
         Ok(())
     }
 
-    /// Verify a bluff proof (optional post-game feature)
+    /// Callback from Arcium: the run-out board, the all-in players' hole cards
+    /// and the showdown result, signed by the cluster
+    pub fn on_run_out_result(
+        ctx: Context<RunOutResultCallback>,
+        output: SignedComputationOutputs<RunOutShowdownOutput>,
+    ) -> Result<()> {
+        verify_arcium_callback(
+            &ctx.accounts.table,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+
+        let (signature, proof_hash) = output_proof(&output);
+        let run_out = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
+
+        let table = &mut ctx.accounts.table;
+        table.community_cards = run_out.community_cards;
+        emit!(CommunityCardsDealt {
+            table_id: table.table_id,
+            cards: table.community_cards,
+        });
+
+        // Players still in an all-in table their cards
+        for (_, player) in seated_players(table, table.key(), ctx.remaining_accounts, None)? {
+            let cards = run_out.hole_cards[player.player_id as usize];
+            if cards[0] != 255 {
+                emit!(HoleCardsRevealed {
+                    table_id: table.table_id,
+                    hand_number: table.hand_number,
+                    player: player.wallet,
+                    cards,
+                });
+            }
+        }
+
+        settle_showdown(
+            table,
+            &mut ctx.accounts.game_result,
+            ctx.remaining_accounts,
+            &run_out.showdown,
+            signature,
+            proof_hash,
+        )
+    }
+
+    /// Verify a bluff proof (optional post-game feature)
     pub fn verify_bluff_proof(
         ctx: Context<VerifyBluff>,
         game_id: u64,
         revealed_card1: u8,
         revealed_card2: u8,
-        arcium_proof: [u8; 128],
+        salt: [u8; 29],
     ) -> Result<()> {
-        // Verify the proof matches the on-chain commitment
         let hand = &ctx.accounts.encrypted_hand;
-        require!(hand.hand_number == game_id, PokerError::InvalidGame);
+        require!(
+            hand.hand_number == game_id && hand.table == ctx.accounts.table.key(),
+            PokerError::InvalidGame
+        );
+        require_keys_eq!(hand.player, ctx.accounts.player.key(), PokerError::NotHandOwner);
+        require!(revealed_card1 < 52 && revealed_card2 < 52, PokerError::InvalidCard);
 
-        // Arcium verifies the proof matches the encrypted hand
-        // If valid, the player cryptographically proves what cards they held
-        verify_arcium_proof(&arcium_proof, &hand.arcium_commitment)?;
+        // Opening the commitment Arcium published when the cards were dealt
+        // proves the player held exactly these cards. The salt travels with
+        // the hole cards, so only the player can produce it.
+        require!(
+            hand_commitment(revealed_card1, revealed_card2, &salt) == hand.arcium_commitment,
+            PokerError::InvalidProof
+        );
 
         emit!(BluffRevealed {
             player: ctx.accounts.player.wallet,
@@ -654,6 +1555,141 @@ pub mod encrypted_poker {
         Ok(())
     }
 
+    /// Queue a minimum-disclosure proof about the player's hand `game_id`:
+    /// the exact cards, only the hand category, or only whether it lost to
+    /// the hand that won the pot. The winner's hand must be that of the
+    /// result's first winner.
+    pub fn prove_hand(
+        ctx: Context<ProveHand>,
+        computation_offset: u64,
+        game_id: u64,
+        disclosure: u8,
+    ) -> Result<()> {
+        require!(disclosure <= DISCLOSE_WEAKER, PokerError::InvalidAction);
+        let table_key = ctx.accounts.table.key();
+        let hand = &ctx.accounts.encrypted_hand;
+        let winner_hand = &ctx.accounts.winner_hand;
+        let result = &ctx.accounts.game_result;
+        require!(hand.arcium_commitment != [0u8; 32], PokerError::InvalidGame);
+
+        let (winner_player, _) = Pubkey::find_program_address(
+            &[b"player", table_key.as_ref(), result.winners[0].as_ref()],
+            &crate::ID,
+        );
+        require!(
+            result.winner_count > 0
+                && winner_hand.player == winner_player
+                && winner_hand.table == table_key
+                && winner_hand.hand_number == game_id,
+            PokerError::InvalidGame
+        );
+        // Ranking a hand takes the whole board
+        if disclosure != DISCLOSE_CARDS {
+            require!(
+                result.community_cards.iter().all(|&card| card != 255),
+                PokerError::BoardIncomplete
+            );
+        }
+        // An uncontested winner never showed a hand to lose to
+        require!(
+            disclosure != DISCLOSE_WEAKER || result.winning_hand_category != NO_SHOWDOWN,
+            PokerError::NoShowdown
+        );
+
+        let mut args = ArgBuilder::new()
+            .x25519_pubkey(hand.encryption_key)
+            .plaintext_u128(hand.nonce)
+            .account(hand.key(), EncryptedHand::CIPHERTEXTS_OFFSET, 64)
+            .x25519_pubkey(winner_hand.encryption_key)
+            .plaintext_u128(winner_hand.nonce)
+            .account(winner_hand.key(), EncryptedHand::CIPHERTEXTS_OFFSET, 64);
+        for card in result.community_cards {
+            args = args.plaintext_u8(card);
+        }
+        let args = args.plaintext_u8(disclosure).build();
+
+        let proof = &mut ctx.accounts.hand_proof;
+        proof.player = ctx.accounts.player.key();
+        proof.table = table_key;
+        proof.hand_number = game_id;
+        proof.disclosure = disclosure;
+        proof.computation_id = computation_offset;
+        proof.winner_commitment = winner_hand.arcium_commitment;
+        proof.verified = false;
+        proof.cards = [255u8; 2];
+        proof.hand_category = 255;
+        proof.weaker_than_winner = 255;
+        proof.bump = ctx.bumps.hand_proof;
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let callback = callback_ix(
+            instruction::OnHandProof::DISCRIMINATOR,
+            comp_def_offset("prove_hand"),
+            computation_offset,
+            &ctx.accounts.mxe_account,
+            &[
+                CallbackAccount { pubkey: table_key, is_writable: false },
+                CallbackAccount { pubkey: ctx.accounts.encrypted_hand.key(), is_writable: false },
+                CallbackAccount { pubkey: ctx.accounts.hand_proof.key(), is_writable: true },
+            ],
+        )?;
+        queue_computation(ctx.accounts, computation_offset, args, vec![callback], 1, 0)?;
+
+        Ok(())
+    }
+
+    /// Callback from Arcium: a hand proof, accepted only if it was computed on
+    /// the committed hands
+    pub fn on_hand_proof(
+        ctx: Context<HandProofCallback>,
+        output: SignedComputationOutputs<ProveHandOutput>,
+    ) -> Result<()> {
+        let proof = &ctx.accounts.hand_proof;
+        verify_computation_callback(
+            ctx.accounts.table.arcium_mxe_account,
+            proof.computation_id,
+            &ctx.accounts.mxe_account,
+            &ctx.accounts.computation_account,
+            &ctx.accounts.instructions_sysvar,
+        )?;
+
+        let (_, proof_hash) = output_proof(&output);
+        let statement = output.verify_output(&ctx.accounts.cluster_account, &ctx.accounts.computation_account)?;
+
+        let hand = &ctx.accounts.encrypted_hand;
+        require!(
+            statement.disclosure == proof.disclosure
+                && statement.commitment == hand.arcium_commitment
+                && statement.winner_commitment == proof.winner_commitment,
+            PokerError::InvalidProof
+        );
+        if statement.disclosure == DISCLOSE_CARDS {
+            let [card1, card2] = statement.cards;
+            require!(
+                hand_commitment(card1, card2, &statement.salt) == hand.arcium_commitment,
+                PokerError::InvalidProof
+            );
+        }
+
+        let proof = &mut ctx.accounts.hand_proof;
+        proof.verified = true;
+        proof.cards = statement.cards;
+        proof.hand_category = statement.hand_category;
+        proof.weaker_than_winner = statement.weaker_than_winner;
+        proof.proof_hash = proof_hash;
+
+        emit!(HandProven {
+            player: proof.player,
+            game_id: proof.hand_number,
+            disclosure: proof.disclosure,
+            cards: proof.cards,
+            hand_category: proof.hand_category,
+            weaker_than_winner: proof.weaker_than_winner,
+        });
+
+        Ok(())
+    }
+
     /// Submit a social reaction (emoji)
     pub fn submit_reaction(ctx: Context<SubmitReaction>, reaction_type: u8) -> Result<()> {
         let player = &mut ctx.accounts.player;
@@ -693,74 +1729,450 @@ pub mod encrypted_poker {
 
 // ===== Helper Functions =====
 
-fn post_blinds(table: &mut Table) -> Result<()> {
-    table.current_bet = table.big_blind;
+/// Queues the deck shuffle for the next hand, clears the table's per-hand
+/// state, moves the button and posts the blinds
+fn begin_hand(
+    accounts: &mut StartGame,
+    sign_pda_bump: u8,
+    computation_offset: u64,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    accounts.sign_pda_account.bump = sign_pda_bump;
+    let table_key = accounts.table.key();
+    let callback = callback_ix(
+        instruction::OnDeckReady::DISCRIMINATOR,
+        comp_def_offset("create_encrypted_deck"),
+        computation_offset,
+        &accounts.mxe_account,
+        &[CallbackAccount { pubkey: table_key, is_writable: true }],
+    )?;
+    queue_computation(
+        accounts,
+        computation_offset,
+        ArgBuilder::new().build(),
+        vec![callback],
+        1,
+        0,
+    )?;
+
+    let table = &mut accounts.table;
+    // The button moves one seat left every hand after the first
+    if table.hand_number > 0 {
+        table.dealer_seat = (table.dealer_seat + 1) % table.current_players;
+    }
+    table.hand_number += 1;
+    table.phase = GamePhase::PreFlop;
+    table.is_bomb_pot = table.bomb_pot_next;
+    table.bomb_pot_next = false;
+    table.arcium_computation_id = computation_offset;
+    table.encrypted_deck_hash = [0u8; 32];
+    table.deck_next_index = 0;
+    table.deck_burn_count = 0;
+    table.community_cards = [255u8; 5];
+    clear_pots(table);
+    let now = Clock::get()?.unix_timestamp;
+    table.last_action_ts = now;
+
+    // Initialize betting state for PreFlop
+    reset_betting_state(table, table_key, remaining_accounts, now)?;
+    post_blinds(table, table_key, remaining_accounts)?;
+
+    emit!(GameStarted {
+        table_id: table.table_id,
+        hand_number: table.hand_number,
+        player_count: table.current_players,
+    });
+
     Ok(())
 }
 
-fn is_betting_complete(table: &Table, remaining_accounts: &[AccountInfo]) -> bool {
-    // Count active and not all-in players
-    let mut active_non_all_in = 0;
-    for acc in remaining_accounts {
-        if let Ok(player) = Player::try_deserialize(&mut &acc.data.borrow()[..]) {
-            if player.is_active && !player.is_all_in {
-                active_non_all_in += 1;
+/// Posts the hand's forced bets and hands the first preflop action to the seat
+/// after the last blind. Antes (or the big blind ante) go in first, then the
+/// small and big blind from the seats after the button and the optional UTG
+/// straddle. Heads-up, the button posts the small blind and acts first
+/// preflop. A bomb pot is antes only, with no preflop betting at all.
+fn post_blinds(table: &mut Table, table_key: Pubkey, remaining_accounts: &[AccountInfo]) -> Result<()> {
+    let player_count = table.current_players;
+    require!(
+        remaining_accounts.len() == player_count as usize,
+        PokerError::InvalidPlayerCount
+    );
+    let mut in_hand = [false; MAX_PLAYERS];
+    for (seat, acc) in remaining_accounts.iter().enumerate() {
+        let player = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+        in_hand[seat] = player.is_active && !player.is_sitting_out;
+    }
+    let dealt_in = in_hand.iter().filter(|&&dealt| dealt).count();
+    require!(dealt_in >= 2, PokerError::NotEnoughPlayers);
+    // Next seat after `seat` that was dealt in
+    let next_in_hand = |seat: u8| {
+        let mut next = (seat + 1) % player_count;
+        while !in_hand[next as usize] {
+            next = (next + 1) % player_count;
+        }
+        next
+    };
+
+    if table.is_bomb_pot {
+        for seat in 0..player_count {
+            let ante = table.forced_bets.bomb_pot_ante;
+            post_forced_bet(table, table_key, remaining_accounts, seat, ante, false)?;
+        }
+        // Everyone is in; the first betting round is on the flop
+        table.current_bet = 0;
+        table.players_acted = table.players_to_act;
+        table.current_turn = 255;
+        return Ok(());
+    }
+
+    let small_blind_seat = if dealt_in == 2 && in_hand[table.dealer_seat as usize] {
+        table.dealer_seat
+    } else {
+        next_in_hand(table.dealer_seat)
+    };
+    let big_blind_seat = next_in_hand(small_blind_seat);
+
+    let ante = table.forced_bets.ante;
+    if ante > 0 {
+        if table.forced_bets.big_blind_ante {
+            let total = ante * dealt_in as u64;
+            post_forced_bet(table, table_key, remaining_accounts, big_blind_seat, total, false)?;
+        } else {
+            for seat in 0..player_count {
+                post_forced_bet(table, table_key, remaining_accounts, seat, ante, false)?;
             }
         }
     }
 
-    // If 1 or 0 players can act, betting is complete for this street
-    if active_non_all_in <= 1 {
-        return true;
+    let (small_blind, big_blind) = (table.small_blind, table.big_blind);
+    post_forced_bet(table, table_key, remaining_accounts, small_blind_seat, small_blind, true)?;
+    post_forced_bet(table, table_key, remaining_accounts, big_blind_seat, big_blind, true)?;
+    table.current_bet = big_blind;
+    table.min_raise = street_min_bet(table);
+    table.full_raise_bet = big_blind;
+    table.raises_this_street = 1;
+    let mut last_blind_seat = big_blind_seat;
+
+    // The straddle is a blind raise to two big blinds by the seat after the
+    // big blind, who then acts last preflop
+    if table.forced_bets.straddle && dealt_in >= 3 {
+        let straddle_seat = next_in_hand(big_blind_seat);
+        post_forced_bet(table, table_key, remaining_accounts, straddle_seat, big_blind * 2, true)?;
+        table.current_bet = big_blind * 2;
+        table.full_raise_bet = big_blind * 2;
+        table.raises_this_street = 2;
+        last_blind_seat = straddle_seat;
+    }
+
+    // Players back from sitting out post the big blind they missed; those
+    // still away start owing one
+    for seat in 0..player_count {
+        let acc = &remaining_accounts[seat as usize];
+        let mut player = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+        if player.is_sitting_out {
+            player.missed_blinds = big_blind;
+            player.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+        } else if player.missed_blinds > 0 {
+            post_forced_bet(table, table_key, remaining_accounts, seat, player.missed_blinds, true)?;
+        }
+    }
+
+    // The blinds still have the option to raise, so nobody has acted yet
+    table.players_acted = 0;
+    table.current_turn = last_blind_seat;
+    advance_turn(table, table_key, remaining_accounts, None)?;
+
+    Ok(())
+}
+
+/// Moves up to `amount` from a seat's stack into the pot; a short stack posts
+/// what it has and is all-in. Blinds are live bets toward the player's
+/// current bet and settle any missed blind, antes are dead money. Seats not
+/// dealt in are skipped.
+fn post_forced_bet(
+    table: &mut Table,
+    table_key: Pubkey,
+    remaining_accounts: &[AccountInfo],
+    seat: u8,
+    amount: u64,
+    live: bool,
+) -> Result<()> {
+    let acc = &remaining_accounts[seat as usize];
+    require!(acc.owner == &crate::ID, PokerError::InvalidGame);
+    let mut player = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+    require!(
+        player.table == table_key && player.player_id == seat,
+        PokerError::InvalidGame
+    );
+    if player.is_all_in || !player.is_active || player.is_sitting_out {
+        return Ok(());
+    }
+
+    let posted = amount.min(player.chip_count);
+    player.chip_count -= posted;
+    if live {
+        player.current_bet += posted;
+        player.missed_blinds = 0;
+    }
+    player.total_contributed += posted;
+    if player.chip_count == 0 {
+        player.is_all_in = true;
+        table.players_to_act = table.players_to_act.saturating_sub(1);
+    }
+    table.pot += posted;
+    table.main_pot += posted;
+    player.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// A player may raise on their first action of a street, or when a full raise
+/// has come in since they last acted. Fixed-limit streets are also capped.
+fn can_raise(table: &Table, player: &Player) -> bool {
+    let capped = table.betting_structure == BettingStructure::FixedLimit
+        && table.raises_this_street >= FIXED_LIMIT_RAISE_CAP;
+    !capped && (!player.has_acted || table.full_raise_bet > player.current_bet)
+}
+
+/// Smallest opening bet or raise on the current street
+fn street_min_bet(table: &Table) -> u64 {
+    match (&table.betting_structure, &table.phase) {
+        (BettingStructure::FixedLimit, GamePhase::Turn | GamePhase::River) => table.big_blind * 2,
+        _ => table.big_blind,
     }
+}
 
-    table.players_acted >= table.players_to_act
+/// Largest raise on top of the current bet: a pot-sized raise in pot-limit
+/// (the pot after calling), exactly one bet in fixed-limit
+fn max_raise(table: &Table, call_amount: u64) -> u64 {
+    match table.betting_structure {
+        BettingStructure::NoLimit => u64::MAX,
+        BettingStructure::PotLimit => table.pot + call_amount,
+        BettingStructure::FixedLimit => street_min_bet(table),
+    }
+}
+
+fn is_betting_complete(table: &Table, table_key: Pubkey, remaining_accounts: &[AccountInfo]) -> Result<bool> {
+    // Active and not all-in players
+    let players = seated_players(table, table_key, remaining_accounts, None)?;
+    let can_act: Vec<_> = players
+        .iter()
+        .filter(|(_, player)| player.is_active && !player.is_all_in)
+        .collect();
+
+    // If 1 or 0 players can act, betting is complete for this street once
+    // the last of them has answered any all-in
+    if can_act.len() <= 1 {
+        return Ok(can_act
+            .iter()
+            .all(|(_, player)| player.current_bet >= table.current_bet));
+    }
+
+    Ok(table.players_acted >= table.players_to_act)
 }
 
-fn reset_betting_state(table: &mut Table, remaining_accounts: &[AccountInfo], now: i64) {
+/// Two or more players are still in the hand and at most one of them can
+/// bet: an all-in, which goes to showdown through run_out_all_in
+fn is_all_in_hand(table: &Table, table_key: Pubkey, remaining_accounts: &[AccountInfo]) -> Result<bool> {
+    let (mut live, mut can_act) = (0, 0);
+    for (_, player) in seated_players(table, table_key, remaining_accounts, None)? {
+        if player.is_active {
+            live += 1;
+            if !player.is_all_in {
+                can_act += 1;
+            }
+        }
+    }
+    Ok(live >= 2 && can_act <= 1)
+}
+
+fn reset_betting_state(
+    table: &mut Table,
+    table_key: Pubkey,
+    remaining_accounts: &[AccountInfo],
+    now: i64,
+) -> Result<()> {
     table.current_bet = 0;
+    table.min_raise = street_min_bet(table);
+    table.full_raise_bet = 0;
+    table.raises_this_street = 0;
     table.players_acted = 0;
     table.last_action_ts = now; // Reset timer for new street
     
-    // Count active, non-all-in players
+    // Clear street bets and count active, non-all-in players
     let mut count = 0;
-    for acc in remaining_accounts {
-        if let Ok(player) = Player::try_deserialize(&mut &acc.data.borrow()[..]) {
-            if player.is_active && !player.is_all_in {
-                count += 1;
-            }
+    for (acc, mut player) in seated_players(table, table_key, remaining_accounts, None)? {
+        player.current_bet = 0;
+        player.has_acted = false;
+        player.try_serialize(&mut &mut acc.try_borrow_mut_data()?[..])?;
+        if player.is_active && !player.is_all_in {
+            count += 1;
         }
     }
     table.players_to_act = count;
 
-    // Set first turn to act (start from dealer + 1)
-    advance_turn(table, remaining_accounts);
+    // First to act is the first live seat after the button
+    table.current_turn = table.dealer_seat;
+    advance_turn(table, table_key, remaining_accounts, None)?;
+
+    Ok(())
 }
 
-fn advance_turn(table: &mut Table, remaining_accounts: &[AccountInfo]) {
-    // Try to find the next active, non-all-in player
-    // We iterate up to current_players times to avoid infinite loops
-    let mut next_turn = table.current_turn;
-    
-    for _ in 0..table.current_players {
-        next_turn = (next_turn + 1) % table.current_players;
-        
-        // Find player account with this ID among remaining_accounts
-        for acc in remaining_accounts {
-            if let Ok(player) = Player::try_deserialize(&mut &acc.data.borrow()[..]) {
-                if player.player_id == next_turn {
-                    if player.is_active && !player.is_all_in {
-                        table.current_turn = next_turn;
-                        return;
-                    }
-                    break; // Found the right player ID, but they're inactive/all-in
-                }
+/// Moves the turn clockwise to the next active, non-all-in player who is not
+/// sitting out. `acting` is the player who just acted, when they are passed as
+/// a named account rather than in remaining_accounts.
+fn advance_turn(
+    table: &mut Table,
+    table_key: Pubkey,
+    remaining_accounts: &[AccountInfo],
+    acting: Option<&Player>,
+) -> Result<()> {
+    let players = seated_players(table, table_key, remaining_accounts, acting)?;
+    let mut by_seat = [None; MAX_PLAYERS];
+    for (_, player) in &players {
+        by_seat[player.seat_index as usize] = Some(player);
+    }
+
+    // Start from the seat of the player whose turn it was, or after the last
+    // seat when nobody's turn is set
+    let everyone = || acting.into_iter().chain(players.iter().map(|(_, player)| player));
+    let start = everyone()
+        .find(|player| player.player_id == table.current_turn)
+        .or_else(|| everyone().max_by_key(|player| player.player_id))
+        .map_or(0, |player| player.seat_index as usize);
+
+    let seat_count = table.max_players as usize;
+    for step in 1..=seat_count {
+        if let Some(player) = by_seat[(start + step) % seat_count] {
+            if player.is_active && !player.is_all_in && !player.is_sitting_out {
+                table.current_turn = player.player_id;
+                return Ok(());
             }
         }
     }
-    
+
     // If no one else can act, set turn to 255 to signal street end
     table.current_turn = 255;
+    Ok(())
+}
+
+/// The table's seated players from `accounts`, in player_id order. Each must
+/// be the Player PDA the seat map holds, passed once; together with `acting`
+/// (a player passed as a named account) they must cover every seat.
+fn seated_players<'a, 'info>(
+    table: &Table,
+    table_key: Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    acting: Option<&Player>,
+) -> Result<Vec<(&'a AccountInfo<'info>, Player)>> {
+    let seat_bit = |player_id: u8| 1u8.checked_shl(player_id as u32).unwrap_or(0);
+    let mut seen = acting.map_or(0, |player| seat_bit(player.player_id));
+    let mut players = Vec::with_capacity(accounts.len());
+    for acc in accounts {
+        require!(acc.owner == &crate::ID, PokerError::InvalidGame);
+        let player = Player::try_deserialize(&mut &acc.data.borrow()[..])?;
+        require!(
+            player.table == table_key
+                && player.player_id < table.current_players
+                && table.seats.get(player.seat_index as usize) == Some(&acc.key()),
+            PokerError::InvalidGame
+        );
+        require!(seen & seat_bit(player.player_id) == 0, PokerError::DuplicatePlayer);
+        seen |= seat_bit(player.player_id);
+        players.push((acc, player));
+    }
+    require!(
+        seen as u32 + 1 == 1u32 << table.current_players,
+        PokerError::InvalidPlayerCount
+    );
+
+    players.sort_by_key(|(_, player)| player.player_id);
+    Ok(players)
+}
+
+/// Creates a program-owned PDA at `target`, the way `init_if_needed` would.
+/// Does nothing if the account was already created.
+fn init_pda_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    if target.owner == &crate::ID {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+    if target.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // Someone pre-funded the address: top up, then allocate and assign
+    let top_up = rent.saturating_sub(target.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign { account_to_assign: target.clone() },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
+
+/// The hole cards and every street up to the current phase have landed, so
+/// the table has no deal computation still pending
+fn is_deal_settled(table: &Table) -> bool {
+    let revealed = table.community_cards.iter().filter(|&&c| c != 255).count();
+    let expected = match table.phase {
+        GamePhase::PreFlop => 0,
+        GamePhase::Flop => 3,
+        GamePhase::Turn => 4,
+        GamePhase::River => 5,
+        _ => return false,
+    };
+    table.deck_next_index > 0 && revealed == expected
+}
+
+/// PreFlop through River, the phases where players still act
+fn is_betting_phase(phase: &GamePhase) -> bool {
+    matches!(
+        phase,
+        GamePhase::PreFlop | GamePhase::Flop | GamePhase::Turn | GamePhase::River
+    )
 }
 
 fn phase_to_u8(phase: &GamePhase) -> u8 {
@@ -775,126 +2187,1048 @@ fn phase_to_u8(phase: &GamePhase) -> u8 {
     }
 }
 
-fn verify_arcium_callback(_mxe: &AccountInfo) -> Result<()> {
-    // Verify the MXE account is owned by Arcium's program
-    // Mock for now since arcium_sdk::ID is unavailable
+fn action_to_u8(action: &PlayerAction) -> u8 {
+    match action {
+        PlayerAction::Fold => 0,
+        PlayerAction::Check => 1,
+        PlayerAction::Call => 2,
+        PlayerAction::Raise(_) => 3,
+        PlayerAction::AllIn => 4,
+    }
+}
+
+/// Per-seat nonce for hole cards encrypted to a player's key.
+/// Derived so no (key, nonce) pair repeats across tables, hands or seats.
+fn hand_nonce(table: &Pubkey, hand_number: u64, seat: u8) -> u128 {
+    let digest = hashv(&[table.as_ref(), &hand_number.to_le_bytes(), &[seat]]).to_bytes();
+    u128::from_le_bytes(digest[..16].try_into().unwrap())
+}
+
+/// Circuit that deals the street following `phase`
+fn street_comp_def_offset(phase: &GamePhase) -> Result<u32> {
+    match phase {
+        GamePhase::PreFlop => Ok(comp_def_offset("deal_flop")),
+        GamePhase::Flop => Ok(comp_def_offset("deal_turn")),
+        GamePhase::Turn => Ok(comp_def_offset("deal_river")),
+        _ => Err(PokerError::InvalidPhase.into()),
+    }
+}
+
+/// Builds the instruction Arcium invokes when a computation finishes: Arcium's
+/// fixed callback accounts first, then `extra_accs` for the callback's context.
+fn callback_ix(
+    discriminator: &[u8],
+    comp_def_offset: u32,
+    computation_offset: u64,
+    mxe_account: &MXEAccount,
+    extra_accs: &[CallbackAccount],
+) -> Result<CallbackInstruction> {
+    let mut accounts = vec![
+        CallbackAccount { pubkey: ARCIUM_PROG_ID, is_writable: false },
+        CallbackAccount { pubkey: derive_comp_def_pda!(comp_def_offset), is_writable: false },
+        CallbackAccount { pubkey: derive_mxe_pda!(), is_writable: false },
+        CallbackAccount {
+            pubkey: derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet),
+            is_writable: false,
+        },
+        CallbackAccount {
+            pubkey: derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet),
+            is_writable: false,
+        },
+        CallbackAccount { pubkey: sysvar::instructions::ID, is_writable: false },
+    ];
+    accounts.extend_from_slice(extra_accs);
+
+    Ok(CallbackInstruction {
+        program_id: crate::ID,
+        discriminator: discriminator.to_vec(),
+        accounts,
+    })
+}
+
+/// Accepts only Arcium's callback for the computation this table last queued,
+/// executed by the table's configured MXE.
+fn verify_arcium_callback(
+    table: &Table,
+    mxe_account: &Account<MXEAccount>,
+    computation_account: &AccountInfo,
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    verify_computation_callback(
+        table.arcium_mxe_account,
+        table.arcium_computation_id,
+        mxe_account,
+        computation_account,
+        instructions_sysvar,
+    )
+}
+
+/// Accepts only Arcium's callback for computation `computation_id`, executed
+/// by `expected_mxe`
+fn verify_computation_callback(
+    expected_mxe: Pubkey,
+    computation_id: u64,
+    mxe_account: &Account<MXEAccount>,
+    computation_account: &AccountInfo,
+    instructions_sysvar: &AccountInfo,
+) -> Result<()> {
+    // The previous instruction must be Arcium's callback_computation
+    validate_callback_ixs(instructions_sysvar, &ARCIUM_PROG_ID)
+        .map_err(|_| error!(PokerError::UnauthorizedCallback))?;
+
+    require_keys_eq!(mxe_account.key(), expected_mxe, PokerError::UnauthorizedCallback);
+    require_keys_eq!(
+        computation_account.key(),
+        derive_comp_pda!(computation_id, mxe_account, PokerError::ClusterNotSet),
+        PokerError::UnauthorizedCallback
+    );
+
     Ok(())
 }
 
-fn verify_arcium_proof(_proof: &[u8; 128], _commitment: &[u8; 32]) -> Result<()> {
-    // Mock proof verification since the original arcium_sdk is unavailable
+/// Everyone else folded: the last player standing takes the pot without a
+/// showdown, so nobody's hole cards are revealed. Does nothing while two or
+/// more players are still in the hand; the hand's GameResult is only created
+/// once it ends here.
+fn award_to_last_player<'info>(
+    table: &mut Account<'info, Table>,
+    folder: &Account<Player>,
+    remaining_accounts: &[AccountInfo],
+    game_result: Option<&AccountInfo<'info>>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    now: i64,
+) -> Result<()> {
+    let table_key = table.key();
+    let others = seated_players(table, table_key, remaining_accounts, Some(folder))?;
+    let standing: Vec<_> = others.iter().filter(|(_, other)| other.is_active).collect();
+    if standing.len() != 1 {
+        return Ok(());
+    }
+
+    let winner_info = standing[0].0;
+    let mut winner = Player::try_deserialize(&mut &winner_info.data.borrow()[..])?;
+    let pot = table.pot;
+    winner.chip_count += pot;
+    winner.try_serialize(&mut &mut winner_info.try_borrow_mut_data()?[..])?;
+
+    let result_info = game_result.ok_or(PokerError::GameResultRequired)?;
+    let hand_number = table.hand_number.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"result", table_key.as_ref(), &hand_number],
+        &crate::ID,
+    );
+    require_keys_eq!(result_info.key(), expected, PokerError::InvalidGame);
+    init_pda_account(
+        payer,
+        result_info,
+        system_program,
+        GameResult::LEN,
+        &[b"result", table_key.as_ref(), &hand_number, &[bump]],
+    )?;
+
+    let mut winners = [Pubkey::default(); MAX_PLAYERS];
+    winners[0] = winner.wallet;
+    let mut payouts = [0u64; MAX_PLAYERS];
+    payouts[0] = pot;
+    let mut participants = [Pubkey::default(); MAX_PLAYERS];
+    let wallets = std::iter::once(folder.wallet).chain(others.iter().map(|(_, other)| other.wallet));
+    for (slot, wallet) in participants.iter_mut().zip(wallets) {
+        *slot = wallet;
+    }
+    let result = GameResult {
+        table: table_key,
+        hand_number: table.hand_number,
+        winners,
+        winner_count: 1,
+        payouts,
+        winning_hand_category: NO_SHOWDOWN,
+        community_cards: table.community_cards,
+        participants,
+        participant_count: others.len() as u8 + 1,
+        arcium_proof: [0u8; 256],
+        proof_hash: [0u8; 32],
+        timestamp: now,
+        bump,
+    };
+    result.try_serialize(&mut &mut result_info.try_borrow_mut_data()?[..])?;
+
+    clear_pots(table);
+    table.current_turn = 255;
+    table.phase = GamePhase::Complete;
+
+    emit!(GameComplete {
+        table_id: table.table_id,
+        hand_number: table.hand_number,
+        winner_count: 1,
+        proof_hash: [0u8; 32],
+    });
+
     Ok(())
 }
 
+/// Splits the contributions into the main pot and side pots. Every distinct
+/// contribution of a live player caps one layer, which the live players who
+/// put in at least that much can win; folded chips stay in the pots they
+/// reached and anything above the last cap goes to the top pot.
+fn build_pots(table: &mut Table, contributions: &[u64; MAX_PLAYERS], live: &[bool; MAX_PLAYERS]) {
+    let mut levels: Vec<u64> = (0..MAX_PLAYERS)
+        .filter(|&i| live[i])
+        .map(|i| contributions[i])
+        .collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots = [0u64; MAX_PLAYERS + 1];
+    let mut eligible = [0u8; MAX_PLAYERS + 1];
+    let mut floor = 0u64;
+    for (layer, &level) in levels.iter().enumerate() {
+        let top = layer + 1 == levels.len();
+        for i in 0..MAX_PLAYERS {
+            let c = contributions[i];
+            let upper = if top { c } else { c.min(level) };
+            pots[layer] += upper.saturating_sub(floor.min(upper));
+            if live[i] && c >= level {
+                eligible[layer] |= 1 << i;
+            }
+        }
+        floor = level;
+    }
+
+    table.main_pot = pots[0];
+    table.main_pot_eligible = eligible[0];
+    table.side_pot_count = levels.len().saturating_sub(1) as u8;
+    table.side_pots.copy_from_slice(&pots[1..]);
+    table.side_pot_eligible.copy_from_slice(&eligible[1..]);
+}
+
+/// Total of the pots `player_id` can win
+fn eligible_winnings(table: &Table, player_id: u8) -> u64 {
+    let bit = 1u8.checked_shl(player_id as u32).unwrap_or(0);
+    let mut total = if table.main_pot_eligible & bit != 0 { table.main_pot } else { 0 };
+    for (pot, eligible) in table.side_pots.iter().zip(table.side_pot_eligible) {
+        if eligible & bit != 0 {
+            total += pot;
+        }
+    }
+    total
+}
+
+fn clear_pots(table: &mut Table) {
+    table.pot = 0;
+    table.main_pot = 0;
+    table.side_pots = [0u64; MAX_PLAYERS];
+    table.side_pot_count = 0;
+    table.main_pot_eligible = 0;
+    table.side_pot_eligible = [0u8; MAX_PLAYERS];
+    table.departed_contributions = [0u64; MAX_PLAYERS];
+}
+
+/// The cluster's BLS signature over a computation's output is its fairness proof
+fn output_proof<O: HasSize + AnchorDeserialize + AnchorSerialize>(
+    output: &SignedComputationOutputs<O>,
+) -> ([u8; 64], [u8; 32]) {
+    match output {
+        SignedComputationOutputs::Success(bytes, signature) => (*signature, hashv(&[bytes]).to_bytes()),
+        _ => ([0u8; 64], [0u8; 32]),
+    }
+}
+
+/// Validates the (Player PDA, EncryptedHand PDA) pairs for a showdown, lays
+/// out its pots and starts its arguments: every seat's hand, contributions
+/// and live flags.
+/// Also returns the callback accounts: table, game result, then the players.
+fn showdown_hand_args(
+    table: &mut Table,
+    table_key: Pubkey,
+    game_result: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<(ArgBuilder, Vec<CallbackAccount>)> {
+    let player_count = table.current_players as usize;
+    require!(
+        player_count > 0 && remaining_accounts.len() == player_count * 2,
+        PokerError::InvalidPlayerCount
+    );
+
+    let mut hands = Vec::with_capacity(player_count);
+    let mut contributions = [0u64; MAX_PLAYERS];
+    let mut live = [false; MAX_PLAYERS];
+    let mut callback_accounts = vec![
+        CallbackAccount { pubkey: table_key, is_writable: true },
+        CallbackAccount { pubkey: game_result, is_writable: true },
+    ];
+
+    for (i, accounts) in remaining_accounts.chunks(2).enumerate() {
+        let (player_info, hand_info) = (&accounts[0], &accounts[1]);
+        require!(
+            player_info.owner == &crate::ID && hand_info.owner == &crate::ID,
+            PokerError::InvalidGame
+        );
+        let player = Player::try_deserialize(&mut &player_info.data.borrow()[..])?;
+        let hand = EncryptedHand::try_deserialize(&mut &hand_info.data.borrow()[..])?;
+        require!(
+            player.table == table_key
+                && player.player_id as usize == i
+                && table.seats[player.seat_index as usize] == player_info.key()
+                && hand.table == table_key
+                && hand.player == player_info.key()
+                && hand.hand_number == table.hand_number,
+            PokerError::InvalidGame
+        );
+
+        contributions[i] = player.total_contributed;
+        live[i] = player.is_active;
+        hands.push((hand_info.key(), hand.encryption_key, hand.nonce));
+        callback_accounts.push(CallbackAccount { pubkey: player_info.key(), is_writable: true });
+    }
+
+    // Players who left mid-hand take the free seats after the seated ones
+    for (slot, &amount) in contributions[player_count..]
+        .iter_mut()
+        .zip(table.departed_contributions.iter().filter(|&&amount| amount > 0))
+    {
+        *slot = amount;
+    }
+
+    // Empty seats repeat the first hand; they are never live
+    build_pots(table, &contributions, &live);
+
+    let mut args = ArgBuilder::new();
+    for seat in 0..MAX_PLAYERS {
+        let (hand_key, encryption_key, nonce) = hands[if seat < player_count { seat } else { 0 }];
+        args = args
+            .plaintext_u8(seat as u8)
+            .x25519_pubkey(encryption_key)
+            .plaintext_u128(nonce)
+            .account(hand_key, EncryptedHand::CIPHERTEXTS_OFFSET, 64);
+    }
+    for amount in contributions {
+        args = args.plaintext_u64(amount);
+    }
+    for is_live in live {
+        args = args.plaintext_bool(is_live);
+    }
+
+    Ok((args, callback_accounts))
+}
+
+/// Records a signed showdown in the hand's GameResult, pays each winner their share
+/// of the pot and completes the hand.
+/// remaining_accounts: the seated Player PDAs, writable.
+fn settle_showdown(
+    table: &mut Account<Table>,
+    result: &mut Account<GameResult>,
+    remaining_accounts: &[AccountInfo],
+    showdown: &ExecuteShowdownOutput,
+    signature: [u8; 64],
+    proof_hash: [u8; 32],
+) -> Result<()> {
+    let winner_count = showdown.winner_count;
+
+    // Store result on-chain
+    result.table = table.key();
+    result.hand_number = table.hand_number;
+    result.winner_count = winner_count;
+    result.winning_hand_category = showdown.winning_hand_category;
+    result.community_cards = table.community_cards;
+    result.arcium_proof[..64].copy_from_slice(&signature);
+    result.proof_hash = proof_hash;
+    result.timestamp = Clock::get()?.unix_timestamp;
+
+    // The showdown instructions attach each seated Player PDA to the
+    // callback, so winners resolve by player_id
+    let players = seated_players(table, table.key(), remaining_accounts, None)?;
+    for (slot, (_, player)) in result.participants.iter_mut().zip(&players) {
+        *slot = player.wallet;
+    }
+    result.participant_count = players.len() as u8;
+
+    // Every chip in the pot has to land in exactly one stack
+    let total_payout = showdown.payouts[..winner_count as usize]
+        .iter()
+        .try_fold(0u64, |sum, &payout| sum.checked_add(payout))
+        .ok_or(PokerError::PayoutMismatch)?;
+    require!(total_payout == table.pot, PokerError::PayoutMismatch);
+
+    for i in 0..(winner_count as usize) {
+        let winner_player_id = showdown.winners[i];
+        result.payouts[i] = showdown.payouts[i];
+
+        // Nobody can be paid more than the pots they were eligible for
+        require!(
+            showdown.payouts[i] <= eligible_winnings(table, winner_player_id),
+            PokerError::PayoutMismatch
+        );
+
+        // Move the winner's share of the pot onto their stack
+        let (winner_info, _) = players
+            .get(winner_player_id as usize)
+            .ok_or(PokerError::PayoutMismatch)?;
+        let mut winner = Player::try_deserialize(&mut &winner_info.data.borrow()[..])?;
+        winner.chip_count += showdown.payouts[i];
+        winner.try_serialize(&mut &mut winner_info.try_borrow_mut_data()?[..])?;
+        result.winners[i] = winner.wallet;
+    }
+
+    clear_pots(table);
+    table.phase = GamePhase::Complete;
+
+    emit!(GameComplete {
+        table_id: table.table_id,
+        hand_number: table.hand_number,
+        winner_count,
+        proof_hash,
+    });
+
+    Ok(())
+}
+
+/// SHA3-256 over card1 || card2 || salt, as computed by the deal circuits
+fn hand_commitment(card1: u8, card2: u8, salt: &[u8; 29]) -> [u8; 32] {
+    let mut hasher = Sha3_256::new();
+    hasher.update([card1, card2]);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
 // ===== Account Contexts =====
 
-#[derive(Accounts)]
-#[instruction(table_id: u64)]
-pub struct CreateTable<'info> {
+/// Implements `QueueCompAccs` the way `#[queue_computation_accounts]` does.
+/// That macro needs the `arcium build` artifacts at compile time and fixes one
+/// circuit per context, while `DealCards` picks its circuit from the table phase.
+macro_rules! impl_queue_comp_accs {
+    ($accounts:ident, $payer:ident, $comp_def_offset:expr) => {
+        impl<'info> QueueCompAccs<'info> for $accounts<'info> {
+            fn comp_def_offset(&self) -> u32 {
+                ($comp_def_offset)(self)
+            }
+
+            fn queue_comp_accs(&self) -> QueueComputation<'info> {
+                QueueComputation {
+                    signer: self.$payer.to_account_info(),
+                    sign_seed: self.sign_pda_account.to_account_info(),
+                    comp: self.computation_account.to_account_info(),
+                    mxe: self.mxe_account.to_account_info(),
+                    mempool: self.mempool_account.to_account_info(),
+                    executing_pool: self.executing_pool.to_account_info(),
+                    comp_def_acc: self.comp_def_account.to_account_info(),
+                    cluster: self.cluster_account.to_account_info(),
+                    pool_account: self.pool_account.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    clock: self.clock_account.to_account_info(),
+                }
+            }
+
+            fn arcium_program(&self) -> AccountInfo<'info> {
+                self.arcium_program.to_account_info()
+            }
+
+            fn mxe_program(&self) -> Pubkey {
+                crate::ID
+            }
+
+            fn signer_pda_bump(&self) -> u8 {
+                self.sign_pda_account.bump
+            }
+        }
+    };
+}
+
+/// Declares the context that registers one circuit's computation definition
+/// with the MXE. Every circuit takes the same accounts, only the name differs.
+macro_rules! init_comp_def_accs {
+    ($accounts:ident, $circuit:tt) => {
+        #[init_computation_definition_accounts($circuit, payer)]
+        #[derive(Accounts)]
+        pub struct $accounts<'info> {
+            #[account(mut)]
+            pub payer: Signer<'info>,
+
+            #[account(mut, address = derive_mxe_pda!())]
+            pub mxe_account: Box<Account<'info, MXEAccount>>,
+
+            /// CHECK: comp_def_account, not initialized yet, checked by the Arcium program
+            #[account(mut)]
+            pub comp_def_account: UncheckedAccount<'info>,
+
+            /// CHECK: address_lookup_table, checked by the Arcium program
+            #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+            pub address_lookup_table: UncheckedAccount<'info>,
+
+            /// CHECK: lut_program, checked by address
+            #[account(address = LUT_PROGRAM_ID)]
+            pub lut_program: UncheckedAccount<'info>,
+
+            pub arcium_program: Program<'info, Arcium>,
+            pub system_program: Program<'info, System>,
+        }
+    };
+}
+
+#[derive(Accounts)]
+#[instruction(table_id: u64)]
+pub struct CreateTable<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Table::LEN,
+        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub table: Account<'info, Table>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub arcium_mxe: Account<'info, MXEAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct JoinTable<'info> {
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Player::LEN,
+        seeds = [b"player", table.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Optional token account for token-gating
+    pub player_token_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct StartGame<'info> {
+    #[account(mut, has_one = creator)]
+    pub table: Account<'info, Table>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("create_encrypted_deck")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+impl_queue_comp_accs!(StartGame, creator, |_| comp_def_offset("create_encrypted_deck"));
+
+#[derive(Accounts)]
+pub struct DeckReadyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("create_encrypted_deck")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+}
+
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DealHoleCards<'info> {
+    #[account(mut, has_one = creator)]
+    pub table: Account<'info, Table>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("deal_all_hole_cards")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    // (Player PDA, EncryptedHand PDA) pairs are passed as remaining_accounts
+}
+
+impl_queue_comp_accs!(DealHoleCards, creator, |_| comp_def_offset("deal_all_hole_cards"));
+
+#[derive(Accounts)]
+pub struct CardsDealtCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("deal_all_hole_cards")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+    // EncryptedHand PDAs are passed as remaining_accounts
+}
+
+#[derive(Accounts)]
+pub struct SubmitAction<'info> {
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+
+    #[account(mut, has_one = table)]
+    pub player: Account<'info, Player>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EncryptedAction::LEN,
+        // Keyed by the Player PDA: player_ids shift when someone leaves mid-hand
+        seeds = [b"action", table.key().as_ref(), &table.hand_number.to_le_bytes(), player.key().as_ref(), &[player.action_count]],
+        bump
+    )]
+    pub encrypted_action: Account<'info, EncryptedAction>,
+
+    /// CHECK: the hand's GameResult PDA, created only by the fold that leaves a
+    /// single player in the hand
+    #[account(mut)]
+    pub game_result: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct TimeoutPlayer<'info> {
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+
+    #[account(
+        mut,
+        has_one = table,
+        constraint = player.player_id == table.current_turn @ PokerError::NotYourTurn
+    )]
+    pub player: Account<'info, Player>,
+
+    /// CHECK: the hand's GameResult PDA, created only by the fold that leaves a
+    /// single player in the hand
+    #[account(mut)]
+    pub game_result: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DealCards<'info> {
+    #[account(mut, has_one = creator)]
+    pub table: Account<'info, Table>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(street_comp_def_offset(&table.phase)?))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+impl_queue_comp_accs!(DealCards, creator, |accs: &DealCards| {
+    street_comp_def_offset(&accs.table.phase).unwrap_or_default()
+});
+
+#[derive(Accounts)]
+pub struct CommunityCardsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(constraint = [
+        derive_comp_def_pda!(comp_def_offset("deal_flop")),
+        derive_comp_def_pda!(comp_def_offset("deal_turn")),
+        derive_comp_def_pda!(comp_def_offset("deal_river")),
+    ]
+    .contains(&comp_def_account.key()) @ PokerError::UnauthorizedCallback)]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub table: Account<'info, Table>,
+}
+
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct TriggerShowdown<'info> {
+    #[account(mut, has_one = creator)]
+    pub table: Account<'info, Table>,
+
     #[account(
         init,
         payer = creator,
-        space = Table::LEN,
-        seeds = [b"table", table_id.to_le_bytes().as_ref()],
+        space = GameResult::LEN,
+        seeds = [b"result", table.key().as_ref(), &table.hand_number.to_le_bytes()],
         bump
     )]
-    pub table: Account<'info, Table>,
+    pub game_result: Account<'info, GameResult>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    /// CHECK: Arcium MXE account
-    pub arcium_mxe: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("execute_showdown")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
 
     pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    // (Player PDA, EncryptedHand PDA) pairs are passed as remaining_accounts
 }
 
+impl_queue_comp_accs!(TriggerShowdown, creator, |_| comp_def_offset("execute_showdown"));
+
 #[derive(Accounts)]
-pub struct JoinTable<'info> {
+pub struct ShowdownResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("execute_showdown")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub table: Account<'info, Table>,
 
+    #[account(
+        mut,
+        seeds = [b"result", table.key().as_ref(), &table.hand_number.to_le_bytes()],
+        bump = game_result.bump
+    )]
+    pub game_result: Account<'info, GameResult>,
+    // Seated Player PDAs (writable) are passed as remaining_accounts to credit the winners
+}
+
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RunOutAllIn<'info> {
+    #[account(mut, has_one = creator)]
+    pub table: Account<'info, Table>,
+
     #[account(
         init,
-        payer = payer,
-        space = Player::LEN,
-        seeds = [b"player", table.key().as_ref(), payer.key().as_ref()],
+        payer = creator,
+        space = GameResult::LEN,
+        seeds = [b"result", table.key().as_ref(), &table.hand_number.to_le_bytes()],
         bump
     )]
-    pub player: Account<'info, Player>,
+    pub game_result: Account<'info, GameResult>,
 
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub creator: Signer<'info>,
 
-    // Optional token account for token-gating
-    pub player_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("run_out_showdown")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    // (Player PDA, EncryptedHand PDA) pairs are passed as remaining_accounts
 }
 
+impl_queue_comp_accs!(RunOutAllIn, creator, |_| comp_def_offset("run_out_showdown"));
+
 #[derive(Accounts)]
-pub struct StartGame<'info> {
-    #[account(mut, has_one = creator)]
-    pub table: Account<'info, Table>,
+pub struct RunOutResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
 
-    pub creator: Signer<'info>,
+    #[account(address = derive_comp_def_pda!(comp_def_offset("run_out_showdown")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
 
-    /// CHECK: Arcium MXE
-    pub arcium_mxe: UncheckedAccount<'info>,
-}
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
 
-#[derive(Accounts)]
-pub struct ArciumCallback<'info> {
     #[account(mut)]
     pub table: Account<'info, Table>,
 
-    /// CHECK: Arcium MXE - validated in instruction
-    pub arcium_mxe: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"result", table.key().as_ref(), &table.hand_number.to_le_bytes()],
+        bump = game_result.bump
+    )]
+    pub game_result: Account<'info, GameResult>,
+    // Seated Player PDAs (writable) are passed as remaining_accounts to credit the winners
+}
 
+#[derive(Accounts)]
+pub struct LeaveTable<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub table: Account<'info, Table>,
 
     #[account(
-        init_if_needed,
-        payer = payer,
-        space = EncryptedHand::LEN,
-        seeds = [b"hand", table.key().as_ref(), &table.hand_number.to_le_bytes(), player.key().as_ref()],
-        bump
+        mut,
+        close = wallet,
+        has_one = table,
+        seeds = [b"player", table.key().as_ref(), wallet.key().as_ref()],
+        bump = player.bump
     )]
-    pub encrypted_hand: Account<'info, EncryptedHand>,
+    pub player: Account<'info, Player>,
+
+    /// CHECK: the hand's GameResult PDA, created only by the fold that leaves a
+    /// single player in the hand
+    #[account(mut)]
+    pub game_result: Option<UncheckedAccount<'info>>,
 
-    /// CHECK: Player account
-    pub player: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitAction<'info> {
-    #[account(mut)]
+pub struct SitOut<'info> {
     pub table: Account<'info, Table>,
 
-    #[account(mut, has_one = table)]
-    pub player: Account<'info, Player>,
-
     #[account(
-        init,
-        payer = payer,
-        space = EncryptedAction::LEN,
-        seeds = [b"action", table.key().as_ref(), &table.hand_number.to_le_bytes(), &[player.player_id], &[player.action_count]],
-        bump
+        mut,
+        has_one = table,
+        seeds = [b"player", table.key().as_ref(), wallet.key().as_ref()],
+        bump = player.bump
     )]
-    pub encrypted_action: Account<'info, EncryptedAction>,
-
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    pub player: Account<'info, Player>,
 
-    pub system_program: Program<'info, System>,
+    pub wallet: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct DealCards<'info> {
+pub struct ScheduleBombPot<'info> {
     #[account(mut, has_one = creator)]
     pub table: Account<'info, Table>,
 
@@ -902,42 +3236,133 @@ pub struct DealCards<'info> {
 }
 
 #[derive(Accounts)]
-pub struct TriggerShowdown<'info> {
-    #[account(mut, has_one = creator)]
+#[instruction(game_id: u64)]
+pub struct VerifyBluff<'info> {
     pub table: Account<'info, Table>,
 
-    pub creator: Signer<'info>,
+    #[account(
+        has_one = table,
+        constraint = player.wallet == payer.key() @ PokerError::NotHandOwner
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), &game_id.to_le_bytes(), player.key().as_ref()],
+        bump = encrypted_hand.bump
+    )]
+    pub encrypted_hand: Account<'info, EncryptedHand>,
+
+    pub payer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ShowdownResult<'info> {
-    #[account(mut, has_one = creator)]
+#[instruction(computation_offset: u64, game_id: u64, disclosure: u8)]
+pub struct ProveHand<'info> {
     pub table: Account<'info, Table>,
 
     #[account(
-        init,
-        payer = creator,
-        space = GameResult::LEN,
-        seeds = [b"result", table.key().as_ref(), &table.hand_number.to_le_bytes()],
-        bump
+        has_one = table,
+        constraint = player.wallet == payer.key() @ PokerError::NotHandOwner
+    )]
+    pub player: Account<'info, Player>,
+
+    #[account(
+        seeds = [b"hand", table.key().as_ref(), &game_id.to_le_bytes(), player.key().as_ref()],
+        bump = encrypted_hand.bump
+    )]
+    pub encrypted_hand: Account<'info, EncryptedHand>,
+
+    #[account(
+        seeds = [b"result", table.key().as_ref(), &game_id.to_le_bytes()],
+        bump = game_result.bump
     )]
     pub game_result: Account<'info, GameResult>,
 
-    /// CHECK: Arcium MXE
-    pub arcium_mxe: UncheckedAccount<'info>,
+    // Checked against the result's first winner in the instruction
+    pub winner_hand: Account<'info, EncryptedHand>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = HandProof::LEN,
+        seeds = [b"proof", encrypted_hand.key().as_ref(), &[disclosure]],
+        bump
+    )]
+    pub hand_proof: Account<'info, HandProof>,
 
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub payer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 9,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: mempool_account, checked by the Arcium program
+    #[account(mut, address = derive_mempool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub mempool_account: UncheckedAccount<'info>,
+
+    /// CHECK: executing_pool, checked by the Arcium program
+    #[account(mut, address = derive_execpool_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub executing_pool: UncheckedAccount<'info>,
+
+    /// CHECK: computation_account, checked by the Arcium program
+    #[account(mut, address = derive_comp_pda!(computation_offset, mxe_account, PokerError::ClusterNotSet))]
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("prove_hand")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(mut, address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
 
     pub system_program: Program<'info, System>,
-    // Seated Player PDAs are passed as remaining_accounts for winner resolution
+    pub arcium_program: Program<'info, Arcium>,
 }
 
+impl_queue_comp_accs!(ProveHand, payer, |_| comp_def_offset("prove_hand"));
+
 #[derive(Accounts)]
-pub struct VerifyBluff<'info> {
-    pub player: Account<'info, Player>,
+pub struct HandProofCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+
+    #[account(address = derive_comp_def_pda!(comp_def_offset("prove_hand")))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+
+    /// CHECK: computation_account, its output signature is verified in the instruction
+    pub computation_account: UncheckedAccount<'info>,
+
+    #[account(address = derive_cluster_pda!(mxe_account, PokerError::ClusterNotSet))]
+    pub cluster_account: Account<'info, Cluster>,
+
+    /// CHECK: instructions sysvar, checked by address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub table: Account<'info, Table>,
+
+    #[account(address = hand_proof.player_hand(&table.key()) @ PokerError::InvalidGame)]
     pub encrypted_hand: Account<'info, EncryptedHand>,
-    pub payer: Signer<'info>,
+
+    #[account(mut, has_one = table)]
+    pub hand_proof: Account<'info, HandProof>,
 }
 
 #[derive(Accounts)]
@@ -947,6 +3372,15 @@ pub struct SubmitReaction<'info> {
     pub wallet: Signer<'info>,
 }
 
+init_comp_def_accs!(InitCreateEncryptedDeckCompDef, "create_encrypted_deck");
+init_comp_def_accs!(InitDealAllHoleCardsCompDef, "deal_all_hole_cards");
+init_comp_def_accs!(InitDealFlopCompDef, "deal_flop");
+init_comp_def_accs!(InitDealTurnCompDef, "deal_turn");
+init_comp_def_accs!(InitDealRiverCompDef, "deal_river");
+init_comp_def_accs!(InitExecuteShowdownCompDef, "execute_showdown");
+init_comp_def_accs!(InitRunOutShowdownCompDef, "run_out_showdown");
+init_comp_def_accs!(InitProveHandCompDef, "prove_hand");
+
 // ===== Errors =====
 #[error_code]
 pub enum PokerError {
@@ -960,6 +3394,8 @@ pub enum PokerError {
     TableFull,
     #[msg("Invalid seat index")]
     InvalidSeat,
+    #[msg("Seat is taken")]
+    SeatTaken,
     #[msg("Token gate required to join this table")]
     TokenGateRequired,
     #[msg("Wrong token mint")]
@@ -980,6 +3416,22 @@ pub enum PokerError {
     InsufficientChips,
     #[msg("Raise too small")]
     RaiseTooSmall,
+    #[msg("Bomb pots are not enabled on this table")]
+    BombPotsDisabled,
+    #[msg("Fold before sitting out")]
+    PlayerInHand,
+    #[msg("Player is not sitting out")]
+    NotSittingOut,
+    #[msg("All-in players stay until the hand ends")]
+    PlayerAllIn,
+    #[msg("Player still has time to act")]
+    TimeNotExpired,
+    #[msg("Nothing to call, check instead")]
+    NothingToCall,
+    #[msg("Raise exceeds the betting limit")]
+    RaiseTooLarge,
+    #[msg("Betting was not reopened by a full raise")]
+    BettingNotReopened,
     #[msg("Invalid action")]
     InvalidAction,
     #[msg("Betting round not complete")]
@@ -994,6 +3446,28 @@ pub enum PokerError {
     InvalidProof,
     #[msg("Invalid game reference")]
     InvalidGame,
+    #[msg("Player account passed more than once")]
+    DuplicatePlayer,
+    #[msg("Run-out needs every live player but one to be all-in")]
+    NotAllIn,
+    #[msg("All-in hands go to showdown through run_out_all_in")]
+    AllInRunOut,
+    #[msg("Game result account is required to close the hand")]
+    GameResultRequired,
+    #[msg("Showdown payouts do not add up to the pot")]
+    PayoutMismatch,
+    #[msg("Hand does not belong to this player")]
+    NotHandOwner,
+    #[msg("Hand did not reach a full board")]
+    BoardIncomplete,
+    #[msg("Pot was won without a showdown")]
+    NoShowdown,
+    #[msg("Encrypted deck is not ready")]
+    DeckNotReady,
+    #[msg("Previous deal has not landed yet")]
+    DealPending,
+    #[msg("Arcium cluster not set")]
+    ClusterNotSet,
 }
 
 // ===== Events =====
@@ -1012,6 +3486,13 @@ pub struct PlayerJoined {
     pub seat: u8,
 }
 
+#[event]
+pub struct PlayerLeft {
+    pub table_id: u64,
+    pub player: Pubkey,
+    pub chip_count: u64, // Stack taken from the table
+}
+
 #[event]
 pub struct GameStarted {
     pub table_id: u64,
@@ -1019,6 +3500,18 @@ pub struct GameStarted {
     pub player_count: u8,
 }
 
+#[event]
+pub struct PlayerBusted {
+    pub table_id: u64,
+    pub player: Pubkey,
+}
+
+#[event]
+pub struct BombPotScheduled {
+    pub table_id: u64,
+    pub hand_number: u64,
+}
+
 #[event]
 pub struct DeckReady {
     pub table_id: u64,
@@ -1028,16 +3521,39 @@ pub struct DeckReady {
 #[event]
 pub struct CardsDealt {
     pub table_id: u64,
-    pub player_id: u8,
+    pub player: Pubkey, // Player PDA
     pub hand_number: u64,
 }
 
 #[event]
 pub struct ActionSubmitted {
+    pub table_id: u64,
+    pub player_id: u8,
+    pub action_type: u8,
+    pub amount: u64, // Chips moved into the pot
+    pub next_player: u8,
+}
+
+#[event]
+pub struct PlayerSatOut {
+    pub table_id: u64,
+    pub player: Pubkey,
+    pub auto: bool, // Sat out by the timeout crank
+}
+
+#[event]
+pub struct PlayerSatIn {
+    pub table_id: u64,
+    pub player: Pubkey,
+}
+
+#[event]
+pub struct PlayerTimedOut {
     pub table_id: u64,
     pub player_id: u8,
     pub action_type: u8,
     pub next_player: u8,
+    pub reward: u64,
 }
 
 #[event]
@@ -1058,6 +3574,14 @@ pub struct ShowdownTriggered {
     pub hand_number: u64,
 }
 
+#[event]
+pub struct HoleCardsRevealed {
+    pub table_id: u64,
+    pub hand_number: u64,
+    pub player: Pubkey,
+    pub cards: [u8; 2],
+}
+
 #[event]
 pub struct GameComplete {
     pub table_id: u64,
@@ -1074,6 +3598,16 @@ pub struct BluffRevealed {
     pub card2: u8,
 }
 
+#[event]
+pub struct HandProven {
+    pub player: Pubkey, // Player PDA
+    pub game_id: u64,
+    pub disclosure: u8,
+    pub cards: [u8; 2],
+    pub hand_category: u8,
+    pub weaker_than_winner: u8,
+}
+
 #[event]
 pub struct ReactionSubmitted {
     pub table: Pubkey,
@@ -1087,3 +3621,165 @@ pub struct ChatMessageSent {
     pub player: Pubkey,
     pub message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_table() -> Table {
+        Table::deserialize(&mut &[0u8; Table::LEN][..]).unwrap()
+    }
+
+    fn empty_player() -> Player {
+        Player::deserialize(&mut &[0u8; Player::LEN][..]).unwrap()
+    }
+
+    fn contributions(amounts: &[u64]) -> [u64; MAX_PLAYERS] {
+        let mut out = [0u64; MAX_PLAYERS];
+        out[..amounts.len()].copy_from_slice(amounts);
+        out
+    }
+
+    fn live(flags: &[bool]) -> [bool; MAX_PLAYERS] {
+        let mut out = [false; MAX_PLAYERS];
+        out[..flags.len()].copy_from_slice(flags);
+        out
+    }
+
+    #[test]
+    fn build_pots_even_contributions_make_one_pot() {
+        let mut table = empty_table();
+        build_pots(&mut table, &contributions(&[200, 200, 200]), &live(&[true, true, true]));
+
+        assert_eq!(table.main_pot, 600);
+        assert_eq!(table.main_pot_eligible, 0b111);
+        assert_eq!(table.side_pot_count, 0);
+        assert_eq!(table.side_pots, [0u64; MAX_PLAYERS]);
+    }
+
+    #[test]
+    fn build_pots_layers_uneven_all_ins() {
+        let mut table = empty_table();
+        build_pots(
+            &mut table,
+            &contributions(&[100, 300, 500, 500]),
+            &live(&[true, true, true, true]),
+        );
+
+        assert_eq!(table.main_pot, 400);
+        assert_eq!(table.main_pot_eligible, 0b1111);
+        assert_eq!(table.side_pot_count, 2);
+        assert_eq!(table.side_pots[..2], [600, 400]);
+        assert_eq!(table.side_pot_eligible[..2], [0b1110, 0b1100]);
+    }
+
+    #[test]
+    fn build_pots_keeps_folded_chips_in_the_pots_they_reached() {
+        let mut table = empty_table();
+        build_pots(
+            &mut table,
+            &contributions(&[100, 200, 150]),
+            &live(&[true, true, false]),
+        );
+
+        // The folder's 150 fills the main pot and part of the side pot
+        assert_eq!(table.main_pot, 300);
+        assert_eq!(table.main_pot_eligible, 0b011);
+        assert_eq!(table.side_pot_count, 1);
+        assert_eq!(table.side_pots[0], 150);
+        assert_eq!(table.side_pot_eligible[0], 0b010);
+    }
+
+    #[test]
+    fn build_pots_sweeps_folded_chips_above_the_top_cap() {
+        let mut table = empty_table();
+        build_pots(
+            &mut table,
+            &contributions(&[100, 100, 300]),
+            &live(&[true, true, false]),
+        );
+
+        assert_eq!(table.main_pot, 500);
+        assert_eq!(table.main_pot_eligible, 0b011);
+        assert_eq!(table.side_pot_count, 0);
+        assert_eq!(eligible_winnings(&table, 0), 500);
+        assert_eq!(eligible_winnings(&table, 2), 0);
+    }
+
+    #[test]
+    fn pot_limit_max_raise_counts_the_pending_call() {
+        // Blinds 50/100 in, first to act faces a 100 call
+        let mut table = empty_table();
+        table.betting_structure = BettingStructure::PotLimit;
+        table.pot = 150;
+        table.current_bet = 100;
+
+        // Pot after calling is 250, so the pot-sized raise is to 350
+        assert_eq!(max_raise(&table, 100), 250);
+        assert_eq!(max_raise(&table, 0), 150);
+    }
+
+    #[test]
+    fn fixed_limit_bets_double_on_the_turn() {
+        let mut table = empty_table();
+        table.betting_structure = BettingStructure::FixedLimit;
+        table.big_blind = 100;
+
+        for (phase, bet) in [
+            (GamePhase::PreFlop, 100),
+            (GamePhase::Flop, 100),
+            (GamePhase::Turn, 200),
+            (GamePhase::River, 200),
+        ] {
+            table.phase = phase;
+            assert_eq!(street_min_bet(&table), bet);
+            assert_eq!(max_raise(&table, 0), bet);
+        }
+
+        table.betting_structure = BettingStructure::NoLimit;
+        assert_eq!(street_min_bet(&table), 100);
+        assert_eq!(max_raise(&table, 0), u64::MAX);
+    }
+
+    #[test]
+    fn fixed_limit_caps_at_four_bets_counting_the_blind() {
+        let mut table = empty_table();
+        table.betting_structure = BettingStructure::FixedLimit;
+        table.phase = GamePhase::PreFlop;
+        table.raises_this_street = 1; // The big blind
+        let player = empty_player();
+
+        // Raise, re-raise, cap
+        for _ in 0..3 {
+            assert!(can_raise(&table, &player));
+            table.raises_this_street += 1;
+        }
+        assert!(!can_raise(&table, &player));
+
+        // No cap outside fixed-limit
+        table.betting_structure = BettingStructure::NoLimit;
+        assert!(can_raise(&table, &player));
+    }
+
+    #[test]
+    fn short_all_in_does_not_reopen_the_raise() {
+        let mut table = empty_table();
+        table.current_bet = 100;
+        table.full_raise_bet = 100;
+        let mut player = empty_player();
+        player.has_acted = true;
+        player.current_bet = 100;
+
+        // Already matched the last full raise
+        assert!(!can_raise(&table, &player));
+
+        // A short all-in to 150 raises the bet without reopening it
+        table.current_bet = 150;
+        assert!(!can_raise(&table, &player));
+
+        // A full raise to 250 does
+        table.current_bet = 250;
+        table.full_raise_bet = 250;
+        assert!(can_raise(&table, &player));
+    }
+}
@@ -4,6 +4,20 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  awaitComputationFinalization,
+  getArciumEnv,
+  getArciumProgram,
+  getClusterAccAddress,
+  getCompDefAccAddress,
+  getCompDefAccOffset,
+  getComputationAccAddress,
+  getExecutingPoolAccAddress,
+  getLookupTableAddress,
+  getMempoolAccAddress,
+  getMXEAccAddress,
+} from "@arcium-hq/client";
+import { randomBytes } from "crypto";
 import { assert } from "chai";
 import { EncryptedPoker } from "../target/types/encrypted_poker";
 
@@ -12,20 +26,123 @@ describe("encrypted-poker", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.EncryptedPoker as Program<EncryptedPoker>;
+  const arciumEnv = getArciumEnv();
+  const mxeAccount = getMXEAccAddress(program.programId);
+
+  const compDefAccount = (circuit: string) =>
+    getCompDefAccAddress(program.programId, Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE());
+
+  // Accounts every instruction that queues an Arcium computation takes
+  const arciumAccounts = (circuit: string, computationOffset: anchor.BN) => ({
+    mxeAccount,
+    mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
+    executingPool: getExecutingPoolAccAddress(arciumEnv.arciumClusterOffset),
+    computationAccount: getComputationAccAddress(arciumEnv.arciumClusterOffset, computationOffset),
+    compDefAccount: compDefAccount(circuit),
+    clusterAccount: getClusterAccAddress(arciumEnv.arciumClusterOffset),
+  });
+
+  const newComputationOffset = () => new anchor.BN(randomBytes(8), "hex");
+
+  const actionPda = (playerPDA: PublicKey, handNumber: number, actionCount: number) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("action"),
+        tablePDA.toBuffer(),
+        new anchor.BN(handNumber).toBuffer("le", 8),
+        playerPDA.toBuffer(),
+        Buffer.from([actionCount]),
+      ],
+      program.programId
+    )[0];
+
+  const handPda = (playerPDA: PublicKey, handNumber: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("hand"), tablePDA.toBuffer(), new anchor.BN(handNumber).toBuffer("le", 8), playerPDA.toBuffer()],
+      program.programId
+    )[0];
+
+  const resultPda = (handNumber: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("result"), tablePDA.toBuffer(), new anchor.BN(handNumber).toBuffer("le", 8)],
+      program.programId
+    )[0];
+
+  // Seated Player PDAs in player_id (seat) order, as remaining accounts
+  const seated = (...players: PublicKey[]) =>
+    players.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+
+  // Submits `action` for the player whose `actionCount`-th action of the hand this is
+  const act = (
+    wallet: Keypair,
+    playerPDA: PublicKey,
+    others: PublicKey[],
+    action: any,
+    handNumber: number,
+    actionCount: number
+  ) =>
+    program.methods
+      .submitAction(action)
+      .accountsPartial({
+        table: tablePDA,
+        player: playerPDA,
+        encryptedAction: actionPda(playerPDA, handNumber, actionCount),
+        gameResult: resultPda(handNumber),
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(seated(...others))
+      .signers([wallet])
+      .rpc();
+
+  // Waits for the deck shuffle queued by `deckComputation`, then deals the
+  // hole cards and waits for them to land so betting can start
+  const dealHoleCards = async (deckComputation: anchor.BN, handNumber: number, ...players: PublicKey[]) => {
+    await awaitComputationFinalization(provider, deckComputation, program.programId, "confirmed");
+
+    const computationOffset = newComputationOffset();
+    await program.methods
+      .dealHoleCards(computationOffset)
+      .accountsPartial({
+        table: tablePDA,
+        creator: dealer.publicKey,
+        ...arciumAccounts("deal_all_hole_cards", computationOffset),
+      })
+      .remainingAccounts(players.flatMap((player) => seated(player, handPda(player, handNumber))))
+      .signers([dealer])
+      .rpc();
+    await awaitComputationFinalization(provider, computationOffset, program.programId, "confirmed");
+  };
+
+  const expectError = async (tx: Promise<unknown>, code: string) => {
+    try {
+      await tx;
+    } catch (err: any) {
+      assert.equal(err.error?.errorCode?.code, code);
+      return;
+    }
+    assert.fail(`expected ${code}`);
+  };
 
   // Test wallets
   const dealer = Keypair.generate();
   const player1 = Keypair.generate();
   const player2 = Keypair.generate();
+  const player3 = Keypair.generate();
 
   const tableId = new anchor.BN(Date.now());
   let tablePDA: PublicKey;
   let player1PDA: PublicKey;
   let player2PDA: PublicKey;
+  let deckComputation: anchor.BN;
+
+  // Stand-in x25519 keys the players' hole cards are encrypted to
+  const player1Enc = Keypair.generate().publicKey.toBytes();
+  const player2Enc = Keypair.generate().publicKey.toBytes();
 
   before(async () => {
     // Airdrop SOL to test wallets
-    for (const wallet of [dealer, player1, player2]) {
+    for (const wallet of [dealer, player1, player2, player3]) {
       const sig = await provider.connection.requestAirdrop(
         wallet.publicKey,
         2 * anchor.web3.LAMPORTS_PER_SOL
@@ -33,6 +150,30 @@ describe("encrypted-poker", () => {
       await provider.connection.confirmTransaction(sig);
     }
 
+    // Register every circuit the program queues with the MXE
+    const mxe = await getArciumProgram(provider).account.mxeAccount.fetch(mxeAccount);
+    const addressLookupTable = getLookupTableAddress(program.programId, mxe.lutOffsetSlot);
+    const compDefInits = {
+      create_encrypted_deck: program.methods.initCreateEncryptedDeckCompDef,
+      deal_all_hole_cards: program.methods.initDealAllHoleCardsCompDef,
+      deal_flop: program.methods.initDealFlopCompDef,
+      deal_turn: program.methods.initDealTurnCompDef,
+      deal_river: program.methods.initDealRiverCompDef,
+      execute_showdown: program.methods.initExecuteShowdownCompDef,
+      run_out_showdown: program.methods.initRunOutShowdownCompDef,
+      prove_hand: program.methods.initProveHandCompDef,
+    };
+    for (const [circuit, init] of Object.entries(compDefInits)) {
+      await init()
+        .accountsPartial({
+          payer: provider.wallet.publicKey,
+          mxeAccount,
+          compDefAccount: compDefAccount(circuit),
+          addressLookupTable,
+        })
+        .rpc({ commitment: "confirmed" });
+    }
+
     // Derive PDAs
     [tablePDA] = PublicKey.findProgramAddressSync(
       [Buffer.from("table"), tableId.toBuffer("le", 8)],
@@ -62,13 +203,16 @@ describe("encrypted-poker", () => {
         new anchor.BN(50),   // big blind
         2,                    // min players
         6,                    // max players
+        { noLimit: {} },      // betting structure
+        { ante: new anchor.BN(0), bigBlindAnte: false, straddle: false, bombPotAnte: new anchor.BN(0) },
+        new anchor.BN(0),     // no timeout reward
         null,                 // no token gate
         new anchor.BN(0)
       )
       .accounts({
         table: tablePDA,
         creator: dealer.publicKey,
-        arciumMxe: SystemProgram.programId, // mock for tests
+        arciumMxe: mxeAccount,
         systemProgram: SystemProgram.programId,
       })
       .signers([dealer])
@@ -86,7 +230,7 @@ describe("encrypted-poker", () => {
 
   it("Player 1 joins the table", async () => {
     await program.methods
-      .joinTable(0) // seat 0
+      .joinTable(0, Array.from(player1Enc)) // seat 0
       .accounts({
         table: tablePDA,
         player: player1PDA,
@@ -110,7 +254,7 @@ describe("encrypted-poker", () => {
 
   it("Player 2 joins the table", async () => {
     await program.methods
-      .joinTable(1) // seat 1
+      .joinTable(1, Array.from(player2Enc)) // seat 1
       .accounts({
         table: tablePDA,
         player: player2PDA,
@@ -128,54 +272,185 @@ describe("encrypted-poker", () => {
     console.log("✅ Player 2 joined, table ready with 2 players");
   });
 
+  it("Rejects joins to a taken or missing seat", async () => {
+    const [player3PDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), tablePDA.toBuffer(), player3.publicKey.toBuffer()],
+      program.programId
+    );
+    const join = (seat: number) =>
+      program.methods
+        .joinTable(seat, Array.from(Keypair.generate().publicKey.toBytes()))
+        .accounts({
+          table: tablePDA,
+          player: player3PDA,
+          payer: player3.publicKey,
+          playerTokenAccount: null,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(seated(player2PDA))
+        .signers([player3])
+        .rpc();
+
+    await expectError(join(1), "SeatTaken"); // Player 2's seat
+    await expectError(join(6), "InvalidSeat"); // 6-max table: seats 0-5
+
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    assert.equal(tableAccount.currentPlayers, 2);
+    assert.ok(tableAccount.seats[1].equals(player2PDA));
+
+    console.log("✅ Seat registry rejects taken seats");
+  });
+
   it("Starts the game", async () => {
+    const computationOffset = newComputationOffset();
+    deckComputation = computationOffset;
     await program.methods
-      .startGame()
-      .accounts({
+      .startGame(computationOffset)
+      .accountsPartial({
         table: tablePDA,
         creator: dealer.publicKey,
-        arciumMxe: SystemProgram.programId,
+        ...arciumAccounts("create_encrypted_deck", computationOffset),
       })
+      .remainingAccounts(seated(player1PDA, player2PDA))
       .signers([dealer])
       .rpc();
 
     const tableAccount = await program.account.table.fetch(tablePDA);
-    assert.deepEqual(tableAccount.phase, { preFLop: {} });
+    assert.deepEqual(tableAccount.phase, { preFlop: {} });
+    assert.equal(tableAccount.arciumComputationId.toString(), computationOffset.toString());
     assert.equal(tableAccount.handNumber.toNumber(), 1);
 
     console.log("✅ Game started, phase: PreFlop");
   });
 
-  it("Player 1 folds", async () => {
-    // Post dealer/blind state manually for test
-    // In real game, Arcium callback handles this
+  it("Posts the blinds from the seats after the button", async () => {
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    const p1 = await program.account.player.fetch(player1PDA);
+    const p2 = await program.account.player.fetch(player2PDA);
+
+    // Heads-up: the button (seat 0) posts the small blind and acts first
+    assert.equal(tableAccount.dealerSeat, 0);
+    assert.equal(p1.currentBet.toNumber(), 25);
+    assert.equal(p1.chipCount.toNumber(), 1975);
+    assert.equal(p2.currentBet.toNumber(), 50);
+    assert.equal(p2.chipCount.toNumber(), 1950);
+    assert.equal(tableAccount.pot.toNumber(), 75);
+    assert.equal(tableAccount.currentBet.toNumber(), 50);
+    assert.equal(tableAccount.currentTurn, 0);
+
+    console.log("✅ Blinds posted, button acts first heads-up");
+  });
 
-    const [actionPDA] = PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("action"),
-        tablePDA.toBuffer(),
-        new anchor.BN(1).toBuffer("le", 8),
-        Buffer.from([0]), // player_id 0
-      ],
-      program.programId
-    );
+  it("Waits for the hole cards before anyone acts", async () => {
+    await expectError(act(player1, player1PDA, [player2PDA], { call: {} }, 1, 0), "DealPending");
+
+    await dealHoleCards(deckComputation, 1, player1PDA, player2PDA);
+
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    assert.isAbove(tableAccount.deckNextIndex, 0);
+    const hand = await program.account.encryptedHand.fetch(handPda(player1PDA, 1));
+    assert.deepEqual(hand.encryptionKey, Array.from(player1Enc));
+
+    console.log("✅ Hole cards dealt");
+  });
 
+  it("Player 1 folds", async () => {
+    // Heads-up the button posts the small blind and acts first preflop
     await program.methods
-      .submitAction(0, new anchor.BN(0)) // fold = action type 0
-      .accounts({
+      .submitAction({ fold: {} })
+      .accountsPartial({
         table: tablePDA,
         player: player1PDA,
-        encryptedAction: actionPDA,
+        encryptedAction: actionPda(player1PDA, 1, 0),
+        gameResult: resultPda(1),
         payer: player1.publicKey,
         systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts(seated(player2PDA))
       .signers([player1])
       .rpc();
 
     const playerAccount = await program.account.player.fetch(player1PDA);
     assert.equal(playerAccount.isActive, false);
 
-    console.log("✅ Player 1 folded");
+    // Last player standing takes the pot without a showdown
+    const winner = await program.account.player.fetch(player2PDA);
+    assert.equal(winner.chipCount.toNumber(), 2025);
+
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    assert.deepEqual(tableAccount.phase, { complete: {} });
+    assert.equal(tableAccount.pot.toNumber(), 0);
+
+    const result = await program.account.gameResult.fetch(resultPda(1));
+    assert.equal(result.winnerCount, 1);
+    assert.ok(result.winners[0].equals(player2.publicKey));
+    assert.equal(result.payouts[0].toNumber(), 75);
+    assert.equal(result.winningHandCategory, 255); // no showdown
+
+    console.log("✅ Player 1 folded, Player 2 takes the pot");
+  });
+
+  it("Moves the button and swaps the blinds for the next hand", async () => {
+    const computationOffset = newComputationOffset();
+    await program.methods
+      .startNextHand(computationOffset)
+      .accountsPartial({
+        table: tablePDA,
+        creator: dealer.publicKey,
+        ...arciumAccounts("create_encrypted_deck", computationOffset),
+      })
+      .remainingAccounts(seated(player1PDA, player2PDA))
+      .signers([dealer])
+      .rpc();
+
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    const p1 = await program.account.player.fetch(player1PDA);
+    const p2 = await program.account.player.fetch(player2PDA);
+
+    assert.equal(tableAccount.handNumber.toNumber(), 2);
+    assert.equal(tableAccount.dealerSeat, 1);
+    assert.equal(p2.currentBet.toNumber(), 25); // new button, small blind
+    assert.equal(p1.currentBet.toNumber(), 50);
+    assert.equal(tableAccount.currentTurn, 1);
+
+    await dealHoleCards(computationOffset, 2, player1PDA, player2PDA);
+
+    console.log("✅ Button moved to seat 1");
+  });
+
+  it("Requires a raise of at least the last raise", async () => {
+    // Raising to 90 only adds 40 on top of the 50 big blind
+    await expectError(act(player2, player2PDA, [player1PDA], { raise: { 0: new anchor.BN(90) } }, 2, 0), "RaiseTooSmall");
+
+    await act(player2, player2PDA, [player1PDA], { raise: { 0: new anchor.BN(1100) } }, 2, 0);
+    const tableAccount = await program.account.table.fetch(tablePDA);
+    assert.equal(tableAccount.currentBet.toNumber(), 1100);
+    assert.equal(tableAccount.minRaise.toNumber(), 1050);
+
+    // A re-raise has to add another 1050
+    await expectError(act(player1, player1PDA, [player2PDA], { raise: { 0: new anchor.BN(1500) } }, 2, 0), "RaiseTooSmall");
+
+    console.log("✅ Min-raise enforced");
+  });
+
+  it("Does not reopen betting after a short all-in", async () => {
+    // Player 1 shoves 1975 total, 875 more: short of a full raise
+    await act(player1, player1PDA, [player2PDA], { allIn: {} }, 2, 0);
+    let tableAccount = await program.account.table.fetch(tablePDA);
+    assert.equal(tableAccount.currentBet.toNumber(), 1975);
+    assert.equal(tableAccount.minRaise.toNumber(), 1050);
+
+    // Player 2 already acted, so they can only call or fold
+    await expectError(act(player2, player2PDA, [player1PDA], { allIn: {} }, 2, 1), "BettingNotReopened");
+    await act(player2, player2PDA, [player1PDA], { call: {} }, 2, 1);
+
+    const p2 = await program.account.player.fetch(player2PDA);
+    assert.equal(p2.currentBet.toNumber(), 1975);
+    tableAccount = await program.account.table.fetch(tablePDA);
+    assert.equal(tableAccount.currentTurn, 255);
+
+    console.log("✅ Short all-in called, action closed");
   });
 
   it("Fails if player tries to act out of turn", async () => {
@@ -216,13 +491,16 @@ describe("encrypted-poker", () => {
         new anchor.BN(200),
         2,
         6,
+        { noLimit: {} },
+        { ante: new anchor.BN(0), bigBlindAnte: false, straddle: false, bombPotAnte: new anchor.BN(0) },
+        new anchor.BN(0),
         mockTokenMint,
         new anchor.BN(100) // require 100 tokens
       )
       .accounts({
         table: tokenGateTablePDA,
         creator: dealer.publicKey,
-        arciumMxe: SystemProgram.programId,
+        arciumMxe: mxeAccount,
         systemProgram: SystemProgram.programId,
       })
       .signers([dealer])